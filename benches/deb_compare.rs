@@ -0,0 +1,346 @@
+//! Benchmarks of Debian version comparison.
+//!
+//! `DebVersion` compares precomputed token keys. The `baseline` module holds
+//! the previous implementation, which kept the version parts as strings and
+//! walked them on every comparison. The `parse_sort` cases include parsing,
+//! where the keys are built, to show the cost of the keys when each version
+//! is compared only a few times.
+
+use criterion::{BatchSize, Criterion, black_box, criterion_group, criterion_main};
+use vers_rs::VersVersionRange;
+use vers_rs::range::VersionRange;
+use vers_rs::schemes::deb::DebVersion;
+
+/// Versions in the style of a Debian archive, in no particular order.
+fn sample_versions() -> Vec<String> {
+    let mut versions = Vec::new();
+    for i in 0..2000u32 {
+        let version = match i % 5 {
+            0 => format!("{}.{}.{}-{}", i % 7, i % 13, i, i % 4 + 1),
+            1 => format!(
+                "1:{}.{}~rc{}-{}+deb12u{}",
+                i % 5,
+                i % 11,
+                i % 3,
+                i % 9,
+                i % 4
+            ),
+            2 => format!("{}.{}+dfsg-{}~bpo11+1", i % 17, i, i % 6),
+            3 => format!("2.{}.{}-0ubuntu{}.{}", i % 40, i % 3, i % 5, i % 2),
+            _ => format!("{}a.{}", 2020 + i % 5, i),
+        };
+        versions.push(version);
+    }
+    versions
+}
+
+fn deb_compare(c: &mut Criterion) {
+    let strings = sample_versions();
+    let versions: Vec<DebVersion> = strings.iter().map(|v| v.parse().unwrap()).collect();
+    let baseline: Vec<baseline::DebVersion> = strings.iter().map(|v| v.parse().unwrap()).collect();
+
+    c.bench_function("sort/key", |b| {
+        b.iter_batched(
+            || versions.clone(),
+            |mut versions| versions.sort(),
+            BatchSize::SmallInput,
+        )
+    });
+    c.bench_function("sort/baseline", |b| {
+        b.iter_batched(
+            || baseline.clone(),
+            |mut versions| versions.sort(),
+            BatchSize::SmallInput,
+        )
+    });
+
+    c.bench_function("parse_sort/key", |b| {
+        b.iter(|| {
+            let mut versions: Vec<DebVersion> =
+                strings.iter().map(|v| v.parse().unwrap()).collect();
+            versions.sort();
+            versions
+        })
+    });
+    c.bench_function("parse_sort/baseline", |b| {
+        b.iter(|| {
+            let mut versions: Vec<baseline::DebVersion> =
+                strings.iter().map(|v| v.parse().unwrap()).collect();
+            versions.sort();
+            versions
+        })
+    });
+
+    let range: VersVersionRange<DebVersion> =
+        "vers:deb/>=1:0.5~rc1|<<1:3.7-2+deb12u1|>=2.10|<<2.20"
+            .parse()
+            .unwrap();
+    c.bench_function("contains/key", |b| {
+        b.iter(|| {
+            versions
+                .iter()
+                .filter(|v| range.contains((*v).clone()).unwrap())
+                .count()
+        })
+    });
+
+    let (a, b) = (&versions[1], &versions[6]);
+    c.bench_function("cmp/key", |bench| {
+        bench.iter(|| black_box(a).cmp(black_box(b)))
+    });
+    let (a, b) = (&baseline[1], &baseline[6]);
+    c.bench_function("cmp/baseline", |bench| {
+        bench.iter(|| black_box(a).cmp(black_box(b)))
+    });
+
+    let (a, b) = (strings[1].as_str(), strings[6].as_str());
+    c.bench_function("parse_cmp/key", |bench| {
+        bench.iter(|| {
+            let a: DebVersion = black_box(a).parse().unwrap();
+            let b: DebVersion = black_box(b).parse().unwrap();
+            a.cmp(&b)
+        })
+    });
+    c.bench_function("parse_cmp/baseline", |bench| {
+        bench.iter(|| {
+            let a: baseline::DebVersion = black_box(a).parse().unwrap();
+            let b: baseline::DebVersion = black_box(b).parse().unwrap();
+            a.cmp(&b)
+        })
+    });
+}
+
+/// `DebVersion` parsing and comparison as of the parent of the commit that
+/// introduced comparison keys, copied verbatim.
+mod baseline {
+    use std::cmp::Ordering;
+    use std::str::FromStr;
+    use vers_rs::VersError;
+    use vers_rs::schemes::deb::DEB_SCHEME;
+
+    /// Macro to create InvalidVersionFormat errors for Debian versions
+    macro_rules! deb_format_error {
+        ($s:expr, $msg:expr) => {
+            VersError::InvalidVersionFormat(DEB_SCHEME.to_string(), $s.to_string(), $msg.into())
+        };
+    }
+
+    /// `DebVersion` before comparison keys: the parts are kept as strings
+    /// and walked on every comparison.
+    #[derive(Clone, Debug, Eq)]
+    pub struct DebVersion {
+        epoch: u64,
+        upstream: String,
+        debian_revision: String,
+    }
+
+    impl FromStr for DebVersion {
+        type Err = VersError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            if s.is_empty() {
+                return Err(deb_format_error!(s, "empty"));
+            }
+
+            // Parse epoch
+            let (epoch, rest) = if let Some(colon) = s.find(':') {
+                let ep_str = &s[..colon];
+                if ep_str.is_empty() {
+                    return Err(deb_format_error!(s, "missing epoch"));
+                }
+                let epoch = ep_str
+                    .parse::<u64>()
+                    .map_err(|e| deb_format_error!(s, format!("invalid epoch: {e}")))?;
+                (epoch, &s[colon + 1..])
+            } else {
+                (0, s)
+            };
+
+            if rest.ends_with('-') {
+                return Err(deb_format_error!(
+                    s,
+                    "trailing '-' with empty debian_revision"
+                ));
+            }
+
+            // Split upstream and debian revision at last '-'
+            let (upstream, debian_revision) = rest
+                .rfind('-')
+                .map(|idx| {
+                    let (u, d) = rest.split_at(idx);
+                    (u, &d[1..])
+                })
+                .unwrap_or((rest, ""));
+
+            // Validate upstream
+            if upstream.is_empty() {
+                return Err(deb_format_error!(s, "missing upstream_version"));
+            }
+
+            if !upstream.chars().next().unwrap().is_ascii_digit() {
+                return Err(deb_format_error!(
+                    s,
+                    "upstream_version must start with a digit"
+                ));
+            }
+
+            for ch in upstream.chars() {
+                if !ch.is_ascii_alphanumeric() && !matches!(ch, '.' | '+' | '-' | '~') {
+                    return Err(deb_format_error!(
+                        s,
+                        format!("invalid character '{ch}' in upstream_version")
+                    ));
+                }
+            }
+
+            // Validate debian_revision when present
+            if !debian_revision.is_empty() {
+                for ch in debian_revision.chars() {
+                    if !ch.is_ascii_alphanumeric() && !matches!(ch, '+' | '.' | '~') {
+                        return Err(deb_format_error!(
+                            s,
+                            format!("invalid character '{ch}' in debian_revision")
+                        ));
+                    }
+                }
+            }
+
+            Ok(DebVersion {
+                epoch,
+                upstream: upstream.to_string(),
+                debian_revision: debian_revision.to_string(),
+            })
+        }
+    }
+
+    impl Ord for DebVersion {
+        fn cmp(&self, other: &Self) -> Ordering {
+            // Compare epochs first
+            match self.epoch.cmp(&other.epoch) {
+                Ordering::Equal => {}
+                ord => return ord,
+            }
+
+            // Compare upstream versions
+            match compare_part(&self.upstream, &other.upstream) {
+                Ordering::Equal => {}
+                ord => return ord,
+            }
+
+            // Compare debian revisions (empty means "0")
+            let rev_a = if self.debian_revision.is_empty() {
+                "0"
+            } else {
+                &self.debian_revision
+            };
+            let rev_b = if other.debian_revision.is_empty() {
+                "0"
+            } else {
+                &other.debian_revision
+            };
+            compare_part(rev_a, rev_b)
+        }
+    }
+
+    impl PartialOrd for DebVersion {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    // Implement PartialEq based on Ord, so that equality is consistent with
+    // ordering. This is necessary because `Ord::cmp` treats an empty debian_revision
+    // as "0", which would diverge from a field-by-field derived equality.
+    impl PartialEq for DebVersion {
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other) == Ordering::Equal
+        }
+    }
+
+    /// Compare two version part strings according to Debian's dpkg algorithm.
+    /// Alternates between comparing non-digit and digit sequences.
+    fn compare_part(a: &str, b: &str) -> Ordering {
+        let mut a = a;
+        let mut b = b;
+
+        loop {
+            // Compare non-digit sequence
+            let ord = compare_non_digit_sequence(&mut a, &mut b);
+            if ord != Ordering::Equal {
+                return ord;
+            }
+
+            // Compare digit sequence
+            let ord = compare_digit_sequence(&mut a, &mut b);
+            if ord != Ordering::Equal {
+                return ord;
+            }
+
+            // If both exhausted, they're equal
+            if a.is_empty() && b.is_empty() {
+                return Ordering::Equal;
+            }
+        }
+    }
+
+    /// Compare non-digit character sequences.
+    /// Returns when both reach a digit or the end of the string.
+    /// Implements Debian's special ordering: '~' < None < letters < other chars
+    fn compare_non_digit_sequence(a: &mut &str, b: &mut &str) -> Ordering {
+        loop {
+            let ca = a.chars().next().filter(|c| !c.is_ascii_digit());
+            let cb = b.chars().next().filter(|c| !c.is_ascii_digit());
+
+            match (ca, cb) {
+                (None, None) => return Ordering::Equal,
+                (Some('~'), Some('~')) => {}
+                (Some('~'), _) => return Ordering::Less,
+                (_, Some('~')) => return Ordering::Greater,
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (Some(c1), Some(c2)) => match non_digit_order(c1).cmp(&non_digit_order(c2)) {
+                    Ordering::Equal => {}
+                    ord => return ord,
+                },
+            }
+
+            // Advance both slices by one character
+            *a = &a[ca.unwrap().len_utf8()..];
+            *b = &b[cb.unwrap().len_utf8()..];
+        }
+    }
+
+    /// The sort weight of a non-digit character other than `~`: letters sort
+    /// by their code, before all other characters.
+    fn non_digit_order(c: char) -> u32 {
+        if c.is_ascii_alphabetic() {
+            c as u32
+        } else {
+            c as u32 + 256
+        }
+    }
+
+    /// Compare digit sequences numerically by length first, then lexicographically.
+    fn compare_digit_sequence(a: &mut &str, b: &mut &str) -> Ordering {
+        // Skip leading zeros
+        *a = a.trim_start_matches('0');
+        *b = b.trim_start_matches('0');
+
+        // Collect digit runs
+        let a_digits: String = a.chars().take_while(|c| c.is_ascii_digit()).collect();
+        let b_digits: String = b.chars().take_while(|c| c.is_ascii_digit()).collect();
+
+        // Advance slices past the digits we collected
+        *a = &a[a_digits.len()..];
+        *b = &b[b_digits.len()..];
+
+        // Compare by length first, then lexicographically
+        match a_digits.len().cmp(&b_digits.len()) {
+            Ordering::Equal => a_digits.cmp(&b_digits),
+            ord => ord,
+        }
+    }
+}
+
+criterion_group!(benches, deb_compare);
+criterion_main!(benches);
@@ -26,6 +26,8 @@ use std::str::FromStr;
 /// - `from_native`: parses a full native range string into vers constraints
 /// - `from_native_constraint`: parses a single native constraint into a vers constraint
 ///
+/// and `to_native` to render a range back into native syntax.
+///
 /// The default `from_native` splits on `|` and delegates to `from_native_constraint`
 /// for each segment. Schemes with entirely different range syntax can override
 /// `from_native` directly.
@@ -86,6 +88,19 @@ pub trait NativeVersionConverter: VersionType {
     fn from_native_constraint(raw: &str) -> Result<VersionConstraint<Self>, VersError> {
         VersionConstraint::<Self>::parse(raw)
     }
+
+    /// Render a range in the scheme's native syntax.
+    ///
+    /// This is the counterpart of [`Self::from_native_string`]. The default
+    /// implementation renders the constraints in vers syntax without the
+    /// `vers:scheme/` prefix, e.g. `>=1.0.0|<2.0.0`. Schemes with native
+    /// operators override this, and return an error for constraints their
+    /// native syntax cannot express.
+    fn to_native(range: &VersVersionRange<Self>) -> Result<String, VersError> {
+        let rendered = range.to_string();
+        let prefix_len = "vers:".len() + range.versioning_scheme.len() + 1;
+        Ok(rendered[prefix_len..].to_string())
+    }
 }
 
 /// A trait alias for version types that can be used in version constraints and ranges.
@@ -164,6 +179,35 @@ impl<V: VersionType> VersionConstraint<V> {
     /// assert_eq!(constraint.version, "1.0.0".parse().unwrap());
     /// ```
     pub fn parse(constraint_str: &str) -> Result<Self, VersError> {
+        Self::parse_with(constraint_str, |version_str| {
+            version_str.parse::<V>().map_err(|_| {
+                VersError::InvalidConstraint(format!("Failed to parse version: {}", version_str))
+            })
+        })
+    }
+
+    /// Parse a version constraint string like [`Self::parse`], using
+    /// `parse_version` to parse the version instead of `FromStr`.
+    ///
+    /// This allows alternative version parsers, such as
+    /// [`SemVer::parse_loose`](crate::schemes::semver::SemVer::parse_loose).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::schemes::semver::SemVer;
+    /// use vers_rs::VersionConstraint;
+    ///
+    /// let constraint = VersionConstraint::parse_with(">=v1.2", |v| {
+    ///     SemVer::parse_loose(v).map(|(version, _)| version)
+    /// })
+    /// .unwrap();
+    /// assert_eq!(constraint.version.to_string(), "1.2.0");
+    /// ```
+    pub fn parse_with(
+        constraint_str: &str,
+        parse_version: impl FnOnce(&str) -> Result<V, VersError>,
+    ) -> Result<Self, VersError> {
         if constraint_str.is_empty() {
             return Err(VersError::InvalidConstraint("Empty constraint".to_string()));
         }
@@ -209,9 +253,7 @@ impl<V: VersionType> VersionConstraint<V> {
             version.to_string()
         };
 
-        let parsed_version = version_str.parse::<V>().map_err(|_| {
-            VersError::InvalidConstraint(format!("Failed to parse version: {}", version_str))
-        })?;
+        let parsed_version = parse_version(&version_str)?;
 
         Ok(Self {
             comparator,
@@ -43,6 +43,12 @@ pub enum VersError {
 
     #[error("Invalid version format for scheme {0}: {1}, error was: {2}")]
     InvalidVersionFormat(String, String, String),
+
+    #[error("Invalid input data: {0}")]
+    InvalidData(String),
+
+    #[error("I/O error: {0}")]
+    Io(String),
 }
 
 /// Convert VersError into a JS exception value when targeting wasm.
@@ -2,6 +2,8 @@
 pub mod comparator;
 pub mod constraint;
 pub mod error;
+#[cfg(feature = "json")]
+pub mod osv;
 pub mod range;
 pub mod schemes;
 
@@ -0,0 +1,550 @@
+//! Conversion between OSV affected ranges and vers ranges.
+//!
+//! [OSV](https://ossf.github.io/osv-schema/) records list the affected
+//! versions of a package as `affected[].ranges`, each a list of events, and
+//! as an explicit `affected[].versions` list:
+//!
+//! ```json
+//! { "package": { "ecosystem": "npm", "name": "lodash" },
+//!   "ranges": [ { "type": "SEMVER", "events": [
+//!       { "introduced": "0" }, { "fixed": "4.17.21" } ] } ],
+//!   "versions": [] }
+//! ```
+//!
+//! This module converts `SEMVER` and `ECOSYSTEM` ranges into a
+//! [`DynamicVersionRange`] of the vers scheme matching the OSV ecosystem, and
+//! a range back into an OSV event list. `GIT` ranges refer to commits rather
+//! than versions and are skipped.
+
+use crate::constraint::VersionType;
+use crate::range::VersionRange;
+use crate::range::interval::{Bound, Interval, IntervalSet};
+use crate::{DynamicVersionRange, VersError, VersVersionRange};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// The package an OSV `affected` entry refers to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OsvPackage {
+    /// The OSV ecosystem, e.g. `npm` or `Debian:12`
+    pub ecosystem: String,
+    /// The package name within the ecosystem
+    pub name: String,
+    /// The package URL, if given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purl: Option<String>,
+}
+
+/// The kind of versions the events of an [`OsvRange`] refer to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OsvRangeType {
+    /// SemVer 2.0 versions, regardless of the ecosystem
+    Semver,
+    /// Versions of the package's ecosystem
+    Ecosystem,
+    /// Git commit hashes
+    Git,
+}
+
+/// An event of an [`OsvRange`], serialized as a single-key object such as
+/// `{"introduced": "1.0.0"}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OsvEvent {
+    /// The first affected version, or `0` for all versions before the next
+    /// event
+    Introduced(String),
+    /// The first version no longer affected
+    Fixed(String),
+    /// The last affected version
+    LastAffected(String),
+    /// An upper limit on the affected versions, or `*` for none
+    Limit(String),
+}
+
+/// A range of affected versions of an OSV `affected` entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OsvRange {
+    /// The kind of versions in `events`
+    #[serde(rename = "type")]
+    pub range_type: OsvRangeType,
+    /// The repository of a `GIT` range
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repo: Option<String>,
+    /// The events, in ascending version order
+    pub events: Vec<OsvEvent>,
+}
+
+/// An entry of the `affected` list of an OSV record.
+///
+/// Fields of the entry not listed here, such as `ecosystem_specific`, are
+/// ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OsvAffected {
+    /// The affected package
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package: Option<OsvPackage>,
+    /// The affected version ranges
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ranges: Vec<OsvRange>,
+    /// Affected versions, in addition to those in `ranges`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub versions: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct OsvRecord {
+    #[serde(default)]
+    affected: Vec<OsvAffected>,
+}
+
+/// The vers scheme of an OSV ecosystem, if this crate supports it.
+///
+/// Release suffixes such as in `Debian:12` or `Ubuntu:22.04:LTS` are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use vers_rs::osv::ecosystem_scheme;
+///
+/// assert_eq!(ecosystem_scheme("Debian:12"), Some("deb"));
+/// assert_eq!(ecosystem_scheme("PyPI"), None);
+/// ```
+pub fn ecosystem_scheme(ecosystem: &str) -> Option<&'static str> {
+    let name = ecosystem.split(':').next().unwrap_or(ecosystem);
+    match name {
+        "npm" => Some("npm"),
+        "Hex" => Some("hex"),
+        "Debian" | "Ubuntu" => Some("deb"),
+        "Pub" => Some("pub"),
+        "CRAN" => Some("cran"),
+        "Hackage" => Some("hackage"),
+        "ConanCenter" => Some("conan"),
+        _ => None,
+    }
+}
+
+/// The OSV ecosystem of a vers scheme, if there is one.
+pub fn scheme_ecosystem(scheme: &str) -> Option<&'static str> {
+    match scheme {
+        "npm" => Some("npm"),
+        "hex" => Some("Hex"),
+        "deb" => Some("Debian"),
+        "pub" => Some("Pub"),
+        "cran" => Some("CRAN"),
+        "hackage" => Some("Hackage"),
+        "conan" => Some("ConanCenter"),
+        _ => None,
+    }
+}
+
+/// Parse the `affected` entries of an OSV record.
+///
+/// # Errors
+///
+/// Returns [`VersError::InvalidData`] if the JSON is not an OSV record.
+pub fn parse_affected(json: &str) -> Result<Vec<OsvAffected>, VersError> {
+    let record: OsvRecord = serde_json::from_str(json)
+        .map_err(|e| VersError::InvalidData(format!("invalid OSV JSON: {}", e)))?;
+    Ok(record.affected)
+}
+
+impl OsvAffected {
+    /// Build an entry for a package from a range.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VersError::UnsupportedVersioningScheme`] if the scheme of
+    /// the range has no OSV ecosystem, and the errors of
+    /// [`OsvRange::from_range`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::osv::OsvAffected;
+    ///
+    /// let range = "vers:npm/<4.17.21".parse().unwrap();
+    /// let affected = OsvAffected::from_range("lodash", &range).unwrap();
+    /// assert_eq!(
+    ///     serde_json::to_string(&affected).unwrap(),
+    ///     r#"{"package":{"ecosystem":"npm","name":"lodash"},"ranges":[{"type":"SEMVER","events":[{"introduced":"0"},{"fixed":"4.17.21"}]}]}"#
+    /// );
+    /// ```
+    pub fn from_range(name: &str, range: &DynamicVersionRange) -> Result<Self, VersError> {
+        let scheme = range.versioning_scheme();
+        let ecosystem = scheme_ecosystem(scheme)
+            .ok_or_else(|| VersError::UnsupportedVersioningScheme(scheme.to_string()))?;
+        Ok(OsvAffected {
+            package: Some(OsvPackage {
+                ecosystem: ecosystem.to_string(),
+                name: name.to_string(),
+                purl: None,
+            }),
+            ranges: vec![OsvRange::from_range(range)?],
+            versions: Vec::new(),
+        })
+    }
+
+    /// Convert the `SEMVER` and `ECOSYSTEM` ranges and the `versions` list of
+    /// this entry into a single range.
+    ///
+    /// The scheme is the one of the package's ecosystem, see
+    /// [`ecosystem_scheme`]. Entries of other ecosystems, or without a
+    /// package, are converted with the `semver` scheme if all their ranges
+    /// are `SEMVER` ranges.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VersError::UnsupportedVersioningScheme`] if no scheme is
+    /// found, a version parsing error for an invalid version, and
+    /// [`VersError::InvalidRange`] if the entry affects no version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::osv::parse_affected;
+    ///
+    /// let json = r#"{"affected": [{
+    ///     "package": {"ecosystem": "Debian:12", "name": "openssl"},
+    ///     "ranges": [{"type": "ECOSYSTEM", "events": [{"introduced": "0"}, {"fixed": "3.0.13-1~deb12u1"}]}]}]}"#;
+    /// let affected = parse_affected(json).unwrap();
+    /// assert_eq!(affected[0].to_range().unwrap().to_string(), "vers:deb/<3.0.13-1~deb12u1");
+    /// ```
+    pub fn to_range(&self) -> Result<DynamicVersionRange, VersError> {
+        let ranges: Vec<_> = self
+            .ranges
+            .iter()
+            .filter(|range| range.range_type != OsvRangeType::Git)
+            .cloned()
+            .collect();
+        let ecosystem = self.package.as_ref().map(|p| p.ecosystem.as_str());
+
+        let scheme = match ecosystem.and_then(ecosystem_scheme) {
+            Some(scheme) => scheme,
+            None if !ranges.is_empty()
+                && ranges.iter().all(|r| r.range_type == OsvRangeType::Semver) =>
+            {
+                "semver"
+            }
+            None => {
+                return Err(VersError::UnsupportedVersioningScheme(
+                    ecosystem.unwrap_or_default().to_string(),
+                ));
+            }
+        };
+        DynamicVersionRange::from_osv(scheme, &ranges, &self.versions)
+    }
+}
+
+impl OsvRange {
+    /// Convert a range into an OSV range.
+    ///
+    /// Ranges of the `semver`, `npm` and `hex` schemes become `SEMVER`
+    /// ranges, all others `ECOSYSTEM` ranges. Each interval of the range
+    /// becomes an `introduced` event, followed by a `fixed` event for an
+    /// exclusive upper bound or a `last_affected` event for an inclusive one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VersError::InvalidRange`] if the range has an exclusive
+    /// lower bound, such as `>1.0` or the gap of `!=1.0`, which OSV events
+    /// cannot express, or matches no version, and
+    /// [`VersError::UnsupportedVersioningScheme`] for fallback ranges.
+    pub fn from_range(range: &DynamicVersionRange) -> Result<Self, VersError> {
+        let range_type = match range.versioning_scheme() {
+            "semver" | "npm" | "hex" => OsvRangeType::Semver,
+            _ => OsvRangeType::Ecosystem,
+        };
+        Ok(OsvRange {
+            range_type,
+            repo: None,
+            events: range.to_osv_events()?,
+        })
+    }
+}
+
+/// Convert OSV ranges and a list of affected versions into a range.
+///
+/// `GIT` ranges are skipped. Within a range the events are sorted by
+/// version and evaluated in that order: `introduced` starts an affected
+/// interval, which `fixed` ends before and `last_affected` after its
+/// version. Versions at or above every `limit` are not affected.
+///
+/// # Errors
+///
+/// Returns a version parsing error for an invalid version, and
+/// [`VersError::InvalidRange`] if no version is affected.
+pub fn range_from_osv<V>(
+    scheme: &str,
+    ranges: &[OsvRange],
+    versions: &[String],
+) -> Result<VersVersionRange<V>, VersError>
+where
+    V: VersionType + FromStr<Err = VersError>,
+{
+    let mut set = IntervalSet::empty();
+    for range in ranges {
+        if range.range_type != OsvRangeType::Git {
+            set = set.union(events_to_set(&range.events)?);
+        }
+    }
+    for version in versions {
+        set = set.union(Interval::exactly(version.parse()?).into());
+    }
+    Ok(VersVersionRange::new(
+        scheme.to_string(),
+        set.to_constraints()?,
+    ))
+}
+
+/// Convert a range into OSV events. See [`OsvRange::from_range`].
+pub fn range_to_events<V: VersionType>(
+    range: &VersVersionRange<V>,
+) -> Result<Vec<OsvEvent>, VersError> {
+    let set = IntervalSet::from_constraints(&range.constraints);
+    if set.is_empty() {
+        return Err(VersError::InvalidRange(
+            "range does not match any version".to_string(),
+        ));
+    }
+
+    let mut events = Vec::new();
+    for interval in set.intervals() {
+        match &interval.lower {
+            None => events.push(OsvEvent::Introduced("0".to_string())),
+            Some(lower) if lower.inclusive => {
+                events.push(OsvEvent::Introduced(lower.version.to_string()))
+            }
+            Some(lower) => {
+                return Err(VersError::InvalidRange(format!(
+                    "OSV events cannot express the exclusive lower bound >{}",
+                    lower.version
+                )));
+            }
+        }
+        match &interval.upper {
+            None => {}
+            Some(upper) if upper.inclusive => {
+                events.push(OsvEvent::LastAffected(upper.version.to_string()))
+            }
+            Some(upper) => events.push(OsvEvent::Fixed(upper.version.to_string())),
+        }
+    }
+    Ok(events)
+}
+
+/// Evaluate the events of one OSV range into the set of affected versions.
+fn events_to_set<V>(events: &[OsvEvent]) -> Result<IntervalSet<V>, VersError>
+where
+    V: VersionType + FromStr<Err = VersError>,
+{
+    // `introduced: 0` and `limit: *` are parsed as `None`, i.e. unbounded
+    let mut bounds: Vec<(Option<V>, &OsvEvent)> = Vec::new();
+    let mut limits = Vec::new();
+    for event in events {
+        match event {
+            OsvEvent::Introduced(version) if version == "0" => bounds.push((None, event)),
+            OsvEvent::Introduced(version)
+            | OsvEvent::Fixed(version)
+            | OsvEvent::LastAffected(version) => bounds.push((Some(version.parse()?), event)),
+            OsvEvent::Limit(version) if version == "*" => limits.push(None),
+            OsvEvent::Limit(version) => limits.push(Some(version.parse::<V>()?)),
+        }
+    }
+    bounds.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut intervals = Vec::new();
+    // The lower bound of the open interval, `Some(None)` if it is unbounded
+    let mut introduced: Option<Option<V>> = None;
+    for (version, event) in bounds {
+        match (event, introduced.take()) {
+            (OsvEvent::Introduced(_), None) => introduced = Some(version),
+            (OsvEvent::Introduced(_), open) => introduced = open,
+            (OsvEvent::Fixed(_) | OsvEvent::LastAffected(_), Some(lower)) => {
+                let inclusive = matches!(event, OsvEvent::LastAffected(_));
+                let bound = |version, inclusive| Bound { version, inclusive };
+                intervals.push(Interval::new(
+                    lower.map(|v| bound(v, true)),
+                    version.map(|v| bound(v, inclusive)),
+                ));
+            }
+            _ => {}
+        }
+    }
+    if let Some(lower) = introduced {
+        intervals.push(match lower {
+            Some(version) => Interval::above(version, true),
+            None => Interval::all(),
+        });
+    }
+
+    let set = IntervalSet::from_intervals(intervals);
+    if limits.is_empty() || limits.iter().any(Option::is_none) {
+        return Ok(set);
+    }
+    let below_limits = limits
+        .into_iter()
+        .flatten()
+        .map(|limit| Interval::below(limit, false))
+        .collect();
+    Ok(set.intersection(&IntervalSet::from_intervals(below_limits)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OsvAffected, OsvEvent, OsvRange, OsvRangeType, parse_affected};
+    use crate::range::VersionRange;
+    use crate::{DynamicVersionRange, VersError};
+
+    const FIXTURE: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/osv-record.json"
+    ));
+
+    fn events(json: &str) -> Vec<OsvEvent> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn import(scheme: &str, events_json: &str) -> Result<String, VersError> {
+        let affected = OsvAffected {
+            package: None,
+            ranges: vec![OsvRange {
+                range_type: OsvRangeType::Ecosystem,
+                repo: None,
+                events: events(events_json),
+            }],
+            versions: Vec::new(),
+        };
+        DynamicVersionRange::from_osv(scheme, &affected.ranges, &affected.versions)
+            .map(|range| range.to_string())
+    }
+
+    #[test]
+    fn test_import_osv_record() {
+        let affected = parse_affected(FIXTURE).unwrap();
+        assert_eq!(affected.len(), 4);
+
+        let ranges: Vec<_> = affected[..3]
+            .iter()
+            .map(|a| a.to_range().unwrap().to_string())
+            .collect();
+        assert_eq!(
+            ranges,
+            [
+                "vers:npm/<1.2.5|>=2.0.0|<2.1.1",
+                "vers:deb/1.1.1w-0+deb11u1|>=3.0.0-1|<=3.0.11-1~deb12u2",
+                "vers:semver/>=0.3.0|<0.5.0",
+            ]
+        );
+        let range = affected[1].to_range().unwrap();
+        assert!(range.contains("3.0.11-1~deb12u2".to_string()).unwrap());
+        assert!(!range.contains("3.0.13-1~deb12u1".to_string()).unwrap());
+
+        assert_eq!(
+            affected[3].to_range(),
+            Err(VersError::UnsupportedVersioningScheme("PyPI".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_import_osv_events() {
+        // Events are sorted before they are evaluated
+        assert_eq!(
+            import(
+                "semver",
+                r#"[{"fixed": "2.0.0"}, {"introduced": "1.0.0"}, {"introduced": "0"}, {"fixed": "0.5.0"}]"#
+            )
+            .unwrap(),
+            "vers:semver/<0.5.0|>=1.0.0|<2.0.0"
+        );
+        assert_eq!(
+            import("semver", r#"[{"introduced": "0"}]"#).unwrap(),
+            "vers:semver/*"
+        );
+        // Each limit caps the range, unless one is "*"
+        assert_eq!(
+            import(
+                "semver",
+                r#"[{"introduced": "1.0.0"}, {"limit": "1.5.0"}, {"limit": "2.0.0"}]"#
+            )
+            .unwrap(),
+            "vers:semver/>=1.0.0|<2.0.0"
+        );
+        assert_eq!(
+            import("semver", r#"[{"introduced": "1.0.0"}, {"limit": "*"}]"#).unwrap(),
+            "vers:semver/>=1.0.0"
+        );
+
+        assert!(matches!(
+            import("semver", r#"[{"introduced": "1.0"}]"#),
+            Err(VersError::InvalidVersionFormat(..))
+        ));
+        assert!(matches!(
+            import("semver", r#"[{"fixed": "1.0.0"}]"#),
+            Err(VersError::InvalidRange(_))
+        ));
+        assert!(matches!(
+            parse_affected(r#"{"affected": [{"ranges": [{"type": "SVN", "events": []}]}]}"#),
+            Err(VersError::InvalidData(_))
+        ));
+    }
+
+    #[test]
+    fn test_export_osv_events() {
+        let export = |range: &str| {
+            let range: DynamicVersionRange = range.parse().unwrap();
+            OsvRange::from_range(&range).map(|r| serde_json::to_string(&r).unwrap())
+        };
+        assert_eq!(
+            export("vers:deb/>=1.0|<<1.5|=2.0|>=3.0|<=3.4").unwrap(),
+            concat!(
+                r#"{"type":"ECOSYSTEM","events":[{"introduced":"1.0"},{"fixed":"1.5"},"#,
+                r#"{"introduced":"2.0"},{"last_affected":"2.0"},"#,
+                r#"{"introduced":"3.0"},{"last_affected":"3.4"}]}"#
+            )
+        );
+        assert_eq!(
+            export("vers:semver/*").unwrap(),
+            r#"{"type":"SEMVER","events":[{"introduced":"0"}]}"#
+        );
+        assert_eq!(
+            export("vers:all/*").unwrap(),
+            r#"{"type":"ECOSYSTEM","events":[{"introduced":"0"}]}"#
+        );
+
+        assert!(matches!(
+            export("vers:npm/>1.0.0"),
+            Err(VersError::InvalidRange(_))
+        ));
+        assert!(matches!(
+            export("vers:npm/!=1.0.0"),
+            Err(VersError::InvalidRange(_))
+        ));
+        assert!(matches!(
+            export("vers:none/*"),
+            Err(VersError::InvalidRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_osv_round_trip() {
+        for range in [
+            "vers:npm/<1.2.5|>=2.0.0|<2.1.1",
+            "vers:deb/=1.1.1w-0+deb11u1|>=3.0.0-1|<=3.0.11-1~deb12u2",
+            "vers:hex/>=1.0.0",
+        ] {
+            let range: DynamicVersionRange = range.parse().unwrap();
+            let affected = OsvAffected::from_range("example", &range).unwrap();
+            let json = serde_json::to_string(&affected).unwrap();
+            let parsed: OsvAffected = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed.to_range().unwrap(), range, "{json}");
+        }
+
+        let range: DynamicVersionRange = "vers:semver/<1.0.0".parse().unwrap();
+        assert_eq!(
+            OsvAffected::from_range("example", &range),
+            Err(VersError::UnsupportedVersioningScheme("semver".to_string()))
+        );
+    }
+}
@@ -1,7 +1,20 @@
-use crate::constraint::NativeVersionConverter;
+use crate::comparator::Comparator;
+use crate::constraint::{NativeVersionConverter, VersionType};
+#[cfg(feature = "json")]
+use crate::osv::{self, OsvEvent, OsvRange};
 use crate::range::VersionRange;
-use crate::schemes::deb::DebVersion;
-use crate::schemes::semver::SemVer;
+use crate::schemes::cocoapods::CocoaPodsVersion;
+use crate::schemes::conan::{ConanVersion, ConanVersionRange};
+use crate::schemes::conda::CondaVersion;
+use crate::schemes::cpan::CpanVersion;
+use crate::schemes::cran::CranVersion;
+use crate::schemes::dart::PubVersion;
+use crate::schemes::deb::{DebMatchMode, DebVersion};
+use crate::schemes::hackage::HackageVersion;
+use crate::schemes::opaque::OpaqueVersion;
+use crate::schemes::openssl::OpenSslVersion;
+use crate::schemes::semver::{Coercion, SemVer};
+use crate::schemes::{hex, npm};
 use crate::{VersError, VersVersionRange, VersionConstraint};
 use std::fmt;
 use std::fmt::{Display, Formatter};
@@ -9,15 +22,103 @@ use std::str::FromStr;
 use std::sync::OnceLock;
 
 /// Internal enum for the actual version range implementation
-#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
-#[serde(tag = "versioning_scheme")]
+#[derive(Debug, Clone, PartialEq, Eq)]
 enum DynamicVersionRangeInner {
-    /// SemVer-based range (for "semver" and "npm" schemes)
-    #[serde(rename = "semver", alias = "npm")]
+    /// SemVer-based range (for "semver", "npm" and "hex" schemes)
     SemVer(VersVersionRange<SemVer>),
     /// Debian dpkg-style versioning ("deb" scheme)
-    #[serde(rename = "deb")]
     Deb(VersVersionRange<DebVersion>),
+    /// OpenSSL letter-suffix versioning ("openssl" scheme)
+    OpenSsl(VersVersionRange<OpenSslVersion>),
+    /// Conan 2 versioning ("conan" scheme), with the pre-release option of
+    /// native Conan ranges
+    Conan(ConanVersionRange),
+    /// Dart pub versioning ("pub" scheme)
+    Pub(VersVersionRange<PubVersion>),
+    /// CocoaPods versioning ("cocoapods" scheme)
+    CocoaPods(VersVersionRange<CocoaPodsVersion>),
+    /// Perl CPAN versioning ("cpan" scheme)
+    Cpan(VersVersionRange<CpanVersion>),
+    /// R CRAN versioning ("cran" scheme)
+    Cran(VersVersionRange<CranVersion>),
+    /// Haskell PVP versioning ("hackage" scheme)
+    Hackage(VersVersionRange<HackageVersion>),
+    /// Conda versioning ("conda" scheme)
+    Conda(VersVersionRange<CondaVersion>),
+    /// Every version of any ecosystem ("vers:all/*")
+    All(VersVersionRange<String>),
+    /// No version of any ecosystem ("vers:none/*")
+    None(VersVersionRange<String>),
+    /// Equality-only range for a scheme without a version type, created by
+    /// [`DynamicVersionRange::parse_with_fallback`]
+    Fallback(VersVersionRange<OpaqueVersion>),
+}
+
+/// The constraints of a serialized range. The versioning scheme is consumed
+/// by serde as the tag of [`SerializedRange`].
+#[derive(serde::Deserialize)]
+#[serde(bound = "V: serde::de::DeserializeOwned")]
+struct SerializedConstraints<V: VersionType> {
+    constraints: Vec<VersionConstraint<V>>,
+}
+
+impl<V: VersionType> SerializedConstraints<V> {
+    fn into_range(self, scheme: &str) -> VersVersionRange<V> {
+        VersVersionRange::new(scheme.to_string(), self.constraints)
+    }
+}
+
+/// The constraints and options of a serialized Conan range.
+#[derive(serde::Deserialize)]
+struct SerializedConanRange {
+    constraints: Vec<VersionConstraint<ConanVersion>>,
+    #[serde(default)]
+    include_prerelease: bool,
+}
+
+/// Serialized form of a [`DynamicVersionRange`], with one variant per scheme
+/// name so that aliases like "npm" keep their name when deserialized.
+#[derive(serde::Deserialize)]
+#[serde(tag = "versioning_scheme", rename_all = "lowercase")]
+enum SerializedRange {
+    Semver(SerializedConstraints<SemVer>),
+    Npm(SerializedConstraints<SemVer>),
+    Hex(SerializedConstraints<SemVer>),
+    Deb(SerializedConstraints<DebVersion>),
+    OpenSsl(SerializedConstraints<OpenSslVersion>),
+    Conan(SerializedConanRange),
+    Pub(SerializedConstraints<PubVersion>),
+    CocoaPods(SerializedConstraints<CocoaPodsVersion>),
+    Cpan(SerializedConstraints<CpanVersion>),
+    Cran(SerializedConstraints<CranVersion>),
+    Hackage(SerializedConstraints<HackageVersion>),
+    Conda(SerializedConstraints<CondaVersion>),
+    All(SerializedConstraints<String>),
+    None(SerializedConstraints<String>),
+}
+
+impl From<SerializedRange> for DynamicVersionRangeInner {
+    fn from(range: SerializedRange) -> Self {
+        match range {
+            SerializedRange::Semver(c) => Self::SemVer(c.into_range("semver")),
+            SerializedRange::Npm(c) => Self::SemVer(c.into_range("npm")),
+            SerializedRange::Hex(c) => Self::SemVer(c.into_range("hex")),
+            SerializedRange::Deb(c) => Self::Deb(c.into_range("deb")),
+            SerializedRange::OpenSsl(c) => Self::OpenSsl(c.into_range("openssl")),
+            SerializedRange::Conan(c) => Self::Conan(ConanVersionRange {
+                range: VersVersionRange::new("conan".to_string(), c.constraints),
+                include_prerelease: c.include_prerelease,
+            }),
+            SerializedRange::Pub(c) => Self::Pub(c.into_range("pub")),
+            SerializedRange::CocoaPods(c) => Self::CocoaPods(c.into_range("cocoapods")),
+            SerializedRange::Cpan(c) => Self::Cpan(c.into_range("cpan")),
+            SerializedRange::Cran(c) => Self::Cran(c.into_range("cran")),
+            SerializedRange::Hackage(c) => Self::Hackage(c.into_range("hackage")),
+            SerializedRange::Conda(c) => Self::Conda(c.into_range("conda")),
+            SerializedRange::All(c) => Self::All(c.into_range("all")),
+            SerializedRange::None(c) => Self::None(c.into_range("none")),
+        }
+    }
 }
 
 /// A dynamic version range that automatically detects the versioning scheme.
@@ -27,7 +128,23 @@ enum DynamicVersionRangeInner {
 /// version range internally.
 ///
 /// It currently supports the following schemes:
-/// - "semver" and "npm" schemes using SemVer version type
+/// - "semver", "npm" and "hex" schemes using SemVer version type
+/// - "deb" scheme using Debian version type
+/// - "openssl" scheme using OpenSSL version type
+/// - "conan" scheme using Conan version type
+/// - "pub" scheme using pub version type
+/// - "cocoapods" scheme using CocoaPods version type
+/// - "cpan" scheme using CPAN version type
+/// - "cran" scheme using CRAN version type
+/// - "hackage" scheme using Hackage (PVP) version type
+/// - "conda" scheme using conda version type
+///
+/// The special ranges `vers:all/*` and `vers:none/*` match every and no
+/// version respectively, regardless of ecosystem. They only allow `*` as
+/// constraint, and their `contains` never parses the candidate version.
+///
+/// Ranges of other schemes can be parsed as equality-only ranges with
+/// [`DynamicVersionRange::parse_with_fallback`].
 ///
 /// # Examples
 ///
@@ -74,6 +191,17 @@ macro_rules! dispatch_inner {
         match $inner {
             DynamicVersionRangeInner::SemVer($range) => $expr,
             DynamicVersionRangeInner::Deb($range) => $expr,
+            DynamicVersionRangeInner::OpenSsl($range) => $expr,
+            DynamicVersionRangeInner::Conan(ConanVersionRange { range: $range, .. }) => $expr,
+            DynamicVersionRangeInner::Pub($range) => $expr,
+            DynamicVersionRangeInner::CocoaPods($range) => $expr,
+            DynamicVersionRangeInner::Cpan($range) => $expr,
+            DynamicVersionRangeInner::Cran($range) => $expr,
+            DynamicVersionRangeInner::Hackage($range) => $expr,
+            DynamicVersionRangeInner::Conda($range) => $expr,
+            DynamicVersionRangeInner::All($range) => $expr,
+            DynamicVersionRangeInner::None($range) => $expr,
+            DynamicVersionRangeInner::Fallback($range) => $expr,
         }
     };
 }
@@ -106,10 +234,39 @@ impl DynamicVersionRange {
     /// ```
     pub fn parse_native(scheme: &str, raw: &str) -> Result<Self, VersError> {
         let inner = match scheme {
-            "semver" | "npm" => {
-                DynamicVersionRangeInner::SemVer(SemVer::from_native_string(scheme, raw)?)
-            }
+            "semver" => DynamicVersionRangeInner::SemVer(SemVer::from_native_string(scheme, raw)?),
+            // npm and hex order versions like SemVer but have their own range syntax
+            "npm" => DynamicVersionRangeInner::SemVer(Self::semver_native_range(
+                scheme,
+                raw,
+                npm::from_native,
+            )?),
+            "hex" => DynamicVersionRangeInner::SemVer(Self::semver_native_range(
+                scheme,
+                raw,
+                hex::from_native,
+            )?),
             "deb" => DynamicVersionRangeInner::Deb(DebVersion::from_native_string(scheme, raw)?),
+            "openssl" => {
+                DynamicVersionRangeInner::OpenSsl(OpenSslVersion::from_native_string(scheme, raw)?)
+            }
+            "conan" => {
+                DynamicVersionRangeInner::Conan(ConanVersionRange::from_native_string(scheme, raw)?)
+            }
+            "pub" => DynamicVersionRangeInner::Pub(PubVersion::from_native_string(scheme, raw)?),
+            "cocoapods" => DynamicVersionRangeInner::CocoaPods(
+                CocoaPodsVersion::from_native_string(scheme, raw)?,
+            ),
+            "cpan" => DynamicVersionRangeInner::Cpan(CpanVersion::from_native_string(scheme, raw)?),
+            "cran" => DynamicVersionRangeInner::Cran(CranVersion::from_native_string(scheme, raw)?),
+            "hackage" => {
+                DynamicVersionRangeInner::Hackage(HackageVersion::from_native_string(scheme, raw)?)
+            }
+            "conda" => {
+                DynamicVersionRangeInner::Conda(CondaVersion::from_native_string(scheme, raw)?)
+            }
+            "all" => DynamicVersionRangeInner::All(Self::star_only_range(scheme, raw)?),
+            "none" => DynamicVersionRangeInner::None(Self::star_only_range(scheme, raw)?),
             _ => return Err(VersError::UnsupportedVersioningScheme(scheme.to_string())),
         };
 
@@ -119,6 +276,373 @@ impl DynamicVersionRange {
         })
     }
 
+    /// Parse a version range specifier string, falling back to an
+    /// equality-only range for unsupported versioning schemes.
+    ///
+    /// Supported schemes are parsed exactly like [`FromStr`] does. For any
+    /// other scheme, versions are kept as opaque strings: only `=`, `!=` and
+    /// `*` are accepted, and `contains` compares version strings for
+    /// identity. The scheme name is preserved, so `Display` round-trips.
+    ///
+    /// Fallback ranges serialize like any other range, but deserializing
+    /// them is not supported, since serde has no way to opt in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::range::dynamic::DynamicVersionRange;
+    /// use vers_rs::range::VersionRange;
+    ///
+    /// let range = DynamicVersionRange::parse_with_fallback("vers:pypi/1.0|1.1").unwrap();
+    /// assert_eq!(range.to_string(), "vers:pypi/1.0|1.1");
+    /// assert!(range.contains("1.1".to_string()).unwrap());
+    /// assert!(DynamicVersionRange::parse_with_fallback("vers:pypi/>=1.0").is_err());
+    /// ```
+    pub fn parse_with_fallback(s: &str) -> Result<Self, VersError> {
+        match s.parse() {
+            Err(VersError::UnsupportedVersioningScheme(_)) => Ok(DynamicVersionRange {
+                inner: DynamicVersionRangeInner::Fallback(s.parse()?),
+                cached_constraints: OnceLock::new(),
+            }),
+            result => result,
+        }
+    }
+
+    /// Parse a native range string like [`Self::parse_native`], falling back
+    /// to an equality-only range in vers syntax for unsupported schemes.
+    ///
+    /// See [`Self::parse_with_fallback`] for the fallback semantics.
+    pub fn parse_native_with_fallback(scheme: &str, raw: &str) -> Result<Self, VersError> {
+        match Self::parse_native(scheme, raw) {
+            Err(VersError::UnsupportedVersioningScheme(_)) => Ok(DynamicVersionRange {
+                inner: DynamicVersionRangeInner::Fallback(OpaqueVersion::from_native_string(
+                    scheme, raw,
+                )?),
+                cached_constraints: OnceLock::new(),
+            }),
+            result => result,
+        }
+    }
+
+    /// Check whether this is an equality-only range for an unsupported
+    /// scheme, created by [`Self::parse_with_fallback`].
+    pub fn is_fallback(&self) -> bool {
+        matches!(self.inner, DynamicVersionRangeInner::Fallback(_))
+    }
+
+    /// Render this range in the native syntax of its versioning scheme.
+    ///
+    /// npm ranges are rendered as node-semver ranges, rejecting `!=`
+    /// constraints; see [`npm::to_native`] for other policies. Schemes
+    /// without a dedicated native syntax render the constraints in vers
+    /// syntax without the `vers:scheme/` prefix. See
+    /// [`NativeVersionConverter::to_native`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VersError::InvalidRange`] for `vers:none/*`, which has no
+    /// native form, and the scheme's error for constraints its native syntax
+    /// cannot express.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::range::dynamic::DynamicVersionRange;
+    ///
+    /// let range = DynamicVersionRange::parse_native("deb", ">=1.0|<<2.0").unwrap();
+    /// assert_eq!(range.to_native().unwrap(), ">=1.0|<<2.0");
+    /// ```
+    pub fn to_native(&self) -> Result<String, VersError> {
+        match &self.inner {
+            DynamicVersionRangeInner::SemVer(range)
+                if range.versioning_scheme == npm::NPM_SCHEME =>
+            {
+                npm::to_native(range, npm::NotEqualPolicy::Reject)
+            }
+            DynamicVersionRangeInner::SemVer(range) => range.to_native(),
+            DynamicVersionRangeInner::Deb(range) => range.to_native(),
+            DynamicVersionRangeInner::OpenSsl(range) => range.to_native(),
+            DynamicVersionRangeInner::Conan(conan) => conan.range.to_native(),
+            DynamicVersionRangeInner::Pub(range) => range.to_native(),
+            DynamicVersionRangeInner::CocoaPods(range) => range.to_native(),
+            DynamicVersionRangeInner::Cpan(range) => range.to_native(),
+            DynamicVersionRangeInner::Cran(range) => range.to_native(),
+            DynamicVersionRangeInner::Hackage(range) => range.to_native(),
+            DynamicVersionRangeInner::Conda(range) => range.to_native(),
+            DynamicVersionRangeInner::All(_) => Ok("*".to_string()),
+            // `*` would match every version, and native syntaxes have no
+            // common way to match none
+            DynamicVersionRangeInner::None(_) => Err(VersError::InvalidRange(
+                "range does not match any version".to_string(),
+            )),
+            DynamicVersionRangeInner::Fallback(range) => range.to_native(),
+        }
+    }
+
+    /// Check if a version string is contained within this range using
+    /// node-semver's pre-release rules.
+    ///
+    /// See [`VersVersionRange::contains_npm`]. Only `npm` ranges follow these
+    /// rules; [`VersionRange::contains`] keeps plain SemVer ordering for
+    /// every scheme.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VersError::UnsupportedVersioningScheme`] if the range does
+    /// not use the `npm` scheme, or an error if the version cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::range::dynamic::DynamicVersionRange;
+    /// use vers_rs::range::VersionRange;
+    ///
+    /// let range: DynamicVersionRange = "vers:npm/>=1.0.0|<2.0.0".parse().unwrap();
+    /// assert!(range.contains("1.5.0-nightly.3".to_string()).unwrap());
+    /// assert!(!range.contains_npm("1.5.0-nightly.3".to_string(), false).unwrap());
+    /// ```
+    pub fn contains_npm(
+        &self,
+        version_str: String,
+        include_prerelease: bool,
+    ) -> Result<bool, VersError> {
+        match &self.inner {
+            DynamicVersionRangeInner::SemVer(range)
+                if range.versioning_scheme == npm::NPM_SCHEME =>
+            {
+                range.contains_npm(version_str.parse()?, include_prerelease)
+            }
+            _ => Err(VersError::UnsupportedVersioningScheme(
+                self.versioning_scheme().to_string(),
+            )),
+        }
+    }
+
+    /// Check if a version string is contained within this Debian range,
+    /// comparing only the parts of the versions selected by `mode`.
+    ///
+    /// See [`VersVersionRange::contains_with_mode`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VersError::UnsupportedVersioningScheme`] if the range is not
+    /// a `deb` range, or an error if the version cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::range::dynamic::DynamicVersionRange;
+    /// use vers_rs::schemes::deb::DebMatchMode;
+    ///
+    /// let range: DynamicVersionRange = "vers:deb/<<1.2.5".parse().unwrap();
+    /// assert!(range.contains_deb("1:1.2.3-4".to_string(), DebMatchMode::UpstreamOnly).unwrap());
+    /// ```
+    pub fn contains_deb(&self, version_str: String, mode: DebMatchMode) -> Result<bool, VersError> {
+        match &self.inner {
+            DynamicVersionRangeInner::Deb(range) => {
+                range.contains_with_mode(version_str.parse()?, mode)
+            }
+            _ => Err(VersError::UnsupportedVersioningScheme(
+                self.versioning_scheme().to_string(),
+            )),
+        }
+    }
+
+    /// Build a range of the given scheme from OSV ranges and affected
+    /// versions. See [`OsvAffected::to_range`](crate::osv::OsvAffected::to_range).
+    #[cfg(feature = "json")]
+    pub(crate) fn from_osv(
+        scheme: &str,
+        ranges: &[OsvRange],
+        versions: &[String],
+    ) -> Result<Self, VersError> {
+        macro_rules! typed {
+            ($variant:ident) => {
+                DynamicVersionRangeInner::$variant(osv::range_from_osv(scheme, ranges, versions)?)
+            };
+        }
+        let inner = match scheme {
+            "semver" | "npm" | "hex" => typed!(SemVer),
+            "deb" => typed!(Deb),
+            "openssl" => typed!(OpenSsl),
+            "conan" => DynamicVersionRangeInner::Conan(
+                osv::range_from_osv::<ConanVersion>(scheme, ranges, versions)?.into(),
+            ),
+            "pub" => typed!(Pub),
+            "cocoapods" => typed!(CocoaPods),
+            "cpan" => typed!(Cpan),
+            "cran" => typed!(Cran),
+            "hackage" => typed!(Hackage),
+            "conda" => typed!(Conda),
+            _ => return Err(VersError::UnsupportedVersioningScheme(scheme.to_string())),
+        };
+        Ok(DynamicVersionRange {
+            inner,
+            cached_constraints: OnceLock::new(),
+        })
+    }
+
+    /// Convert this range into OSV events. See
+    /// [`OsvRange::from_range`](crate::osv::OsvRange::from_range).
+    #[cfg(feature = "json")]
+    pub(crate) fn to_osv_events(&self) -> Result<Vec<OsvEvent>, VersError> {
+        match &self.inner {
+            DynamicVersionRangeInner::All(_) => Ok(vec![OsvEvent::Introduced("0".to_string())]),
+            DynamicVersionRangeInner::None(_) => Err(VersError::InvalidRange(
+                "range does not match any version".to_string(),
+            )),
+            DynamicVersionRangeInner::Fallback(_) => Err(VersError::UnsupportedVersioningScheme(
+                self.versioning_scheme().to_string(),
+            )),
+            inner => dispatch_inner!(inner, range => osv::range_to_events(range)),
+        }
+    }
+
+    /// Parse a version range specifier string, coercing loosely formatted
+    /// versions of the `semver` and `npm` schemes.
+    ///
+    /// Each constraint version is parsed with [`SemVer::parse_loose`], so
+    /// `vers:npm/>=v1.2|<2` is read as `vers:npm/>=1.2.0|<2.0.0`. Ranges of
+    /// other schemes are parsed exactly like [`FromStr`] does.
+    ///
+    /// Returns the range together with the coercions applied to any of its
+    /// versions, each listed once in the order they were first applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::range::dynamic::DynamicVersionRange;
+    /// use vers_rs::schemes::semver::Coercion;
+    ///
+    /// let (range, coercions) = DynamicVersionRange::parse_loose("vers:npm/>=v1.2|<2").unwrap();
+    /// assert_eq!(range.to_string(), "vers:npm/>=1.2.0|<2.0.0");
+    /// assert_eq!(coercions, [Coercion::StrippedPrefix, Coercion::PaddedComponents]);
+    /// ```
+    pub fn parse_loose(s: &str) -> Result<(Self, Vec<Coercion>), VersError> {
+        let scheme = Self::extract_versioning_scheme(s)?;
+        if !matches!(scheme.as_str(), "semver" | "npm") {
+            return Ok((s.parse()?, Vec::new()));
+        }
+
+        let compact = s.replace(|c: char| c.is_whitespace(), "");
+        let constraints_str = compact.split_once('/').map_or("", |(_, c)| c);
+        if constraints_str.is_empty() {
+            return Err(VersError::EmptyConstraints);
+        }
+        let mut coercions = Vec::new();
+        let constraints = constraints_str
+            .split('|')
+            .map(|c| {
+                VersionConstraint::parse_with(c, |v| {
+                    let (version, applied) = SemVer::parse_loose(v)?;
+                    for coercion in applied {
+                        if !coercions.contains(&coercion) {
+                            coercions.push(coercion);
+                        }
+                    }
+                    Ok(version)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut range = VersVersionRange::new(scheme, constraints);
+        range.normalize_and_validate()?;
+        let range = DynamicVersionRange {
+            inner: DynamicVersionRangeInner::SemVer(range),
+            cached_constraints: OnceLock::new(),
+        };
+        Ok((range, coercions))
+    }
+
+    /// Check if a version string is contained within this range, coercing a
+    /// loosely formatted version for the `semver` and `npm` schemes.
+    ///
+    /// The version is parsed with [`SemVer::parse_loose`], and the result is
+    /// returned together with the coercions applied to it; for other schemes
+    /// this is the same as [`VersionRange::contains`] and no coercions are
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::range::dynamic::DynamicVersionRange;
+    /// use vers_rs::schemes::semver::Coercion;
+    ///
+    /// let range: DynamicVersionRange = "vers:npm/>=1.0.0|<2.0.0".parse().unwrap();
+    /// let (contained, coercions) = range.contains_loose("v1.2".to_string()).unwrap();
+    /// assert!(contained);
+    /// assert_eq!(coercions, [Coercion::StrippedPrefix, Coercion::PaddedComponents]);
+    /// assert!(!range.contains_loose("2.0.0.1".to_string()).unwrap().0);
+    /// ```
+    pub fn contains_loose(&self, version_str: String) -> Result<(bool, Vec<Coercion>), VersError> {
+        match &self.inner {
+            DynamicVersionRangeInner::SemVer(range)
+                if matches!(range.versioning_scheme.as_str(), "semver" | "npm") =>
+            {
+                let (version, coercions) = SemVer::parse_loose(&version_str)?;
+                Ok((range.contains(version)?, coercions))
+            }
+            _ => Ok((self.contains(version_str)?, Vec::new())),
+        }
+    }
+
+    /// Check whether this range matches every version.
+    ///
+    /// This is the case for `vers:all/*` and for a `*` range of any scheme.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::range::dynamic::DynamicVersionRange;
+    ///
+    /// let range: DynamicVersionRange = "vers:all/*".parse().unwrap();
+    /// assert!(range.matches_all_versions());
+    /// let range: DynamicVersionRange = "vers:npm/*".parse().unwrap();
+    /// assert!(range.matches_all_versions());
+    /// ```
+    pub fn matches_all_versions(&self) -> bool {
+        match &self.inner {
+            DynamicVersionRangeInner::All(_) => true,
+            DynamicVersionRangeInner::None(_) => false,
+            inner => dispatch_inner!(inner, range => {
+                range.constraints.len() == 1 && range.constraints[0].comparator == Comparator::Any
+            }),
+        }
+    }
+
+    /// Check whether this range matches no version at all, i.e. is `vers:none/*`.
+    pub fn matches_no_versions(&self) -> bool {
+        matches!(self.inner, DynamicVersionRangeInner::None(_))
+    }
+
+    /// Build the range for the special `all` and `none` schemes, which only
+    /// allow `*` as constraint.
+    fn star_only_range(scheme: &str, raw: &str) -> Result<VersVersionRange<String>, VersError> {
+        match raw.trim() {
+            "" => Err(VersError::EmptyConstraints),
+            "*" => Ok(VersVersionRange::new(
+                scheme.to_string(),
+                vec![VersionConstraint::new(Comparator::Any, String::new())],
+            )),
+            _ => Err(VersError::InvalidRange(format!(
+                "the \"{}\" versioning scheme only allows \"*\"",
+                scheme
+            ))),
+        }
+    }
+
+    /// Build a SemVer range of a scheme with its own native range syntax,
+    /// parsed into constraints by `from_native`.
+    fn semver_native_range(
+        scheme: &str,
+        raw: &str,
+        from_native: fn(&str) -> Result<Vec<VersionConstraint<SemVer>>, VersError>,
+    ) -> Result<VersVersionRange<SemVer>, VersError> {
+        let mut range = VersVersionRange::new(scheme.to_string(), from_native(raw)?);
+        range.normalize_and_validate()?;
+        Ok(range)
+    }
+
     /// Extract the versioning scheme from a version range specifier string.
     ///
     /// This is a helper function used internally to determine which version type
@@ -206,6 +730,35 @@ impl VersionRange<String> for DynamicVersionRange {
             DynamicVersionRangeInner::Deb(range) => {
                 range.contains(version_str.parse::<DebVersion>()?)
             }
+            DynamicVersionRangeInner::OpenSsl(range) => {
+                range.contains(version_str.parse::<OpenSslVersion>()?)
+            }
+            DynamicVersionRangeInner::Conan(range) => {
+                range.contains(&version_str.parse::<ConanVersion>()?)
+            }
+            DynamicVersionRangeInner::Pub(range) => {
+                range.contains(version_str.parse::<PubVersion>()?)
+            }
+            DynamicVersionRangeInner::CocoaPods(range) => {
+                range.contains(version_str.parse::<CocoaPodsVersion>()?)
+            }
+            DynamicVersionRangeInner::Cpan(range) => {
+                range.contains(version_str.parse::<CpanVersion>()?)
+            }
+            DynamicVersionRangeInner::Cran(range) => {
+                range.contains(version_str.parse::<CranVersion>()?)
+            }
+            DynamicVersionRangeInner::Hackage(range) => {
+                range.contains(version_str.parse::<HackageVersion>()?)
+            }
+            DynamicVersionRangeInner::Conda(range) => {
+                range.contains(version_str.parse::<CondaVersion>()?)
+            }
+            DynamicVersionRangeInner::All(_) => Ok(true),
+            DynamicVersionRangeInner::None(_) => Ok(false),
+            DynamicVersionRangeInner::Fallback(range) => {
+                range.contains(version_str.parse::<OpaqueVersion>()?)
+            }
         }
     }
 
@@ -267,8 +820,31 @@ impl FromStr for DynamicVersionRange {
         let versioning_scheme = DynamicVersionRange::extract_versioning_scheme(s)?;
 
         let inner = match versioning_scheme.as_str() {
-            "semver" | "npm" => DynamicVersionRangeInner::SemVer(s.parse()?),
+            "semver" | "npm" | "hex" => DynamicVersionRangeInner::SemVer(s.parse()?),
             "deb" => DynamicVersionRangeInner::Deb(s.parse()?),
+            "openssl" => DynamicVersionRangeInner::OpenSsl(s.parse()?),
+            "conan" => {
+                DynamicVersionRangeInner::Conan(s.parse::<VersVersionRange<ConanVersion>>()?.into())
+            }
+            "pub" => DynamicVersionRangeInner::Pub(s.parse()?),
+            "cocoapods" => DynamicVersionRangeInner::CocoaPods(s.parse()?),
+            "cpan" => DynamicVersionRangeInner::Cpan(s.parse()?),
+            "cran" => DynamicVersionRangeInner::Cran(s.parse()?),
+            "hackage" => DynamicVersionRangeInner::Hackage(s.parse()?),
+            "conda" => DynamicVersionRangeInner::Conda(s.parse()?),
+            "all" | "none" => {
+                let constraints = s
+                    .replace(|c: char| c.is_whitespace(), "")
+                    .split_once('/')
+                    .map(|(_, constraints)| constraints.to_string())
+                    .unwrap_or_default();
+                let range = Self::star_only_range(&versioning_scheme, &constraints)?;
+                if versioning_scheme == "all" {
+                    DynamicVersionRangeInner::All(range)
+                } else {
+                    DynamicVersionRangeInner::None(range)
+                }
+            }
             _ => return Err(VersError::UnsupportedVersioningScheme(versioning_scheme)),
         };
 
@@ -279,6 +855,9 @@ impl FromStr for DynamicVersionRange {
     }
 }
 
+/// Formats the range as a vers string. The `include_prerelease` option of a
+/// Conan range has no vers form and is dropped, so such a range parses back
+/// with the default of a native Conan range, which excludes pre-releases.
 impl Display for DynamicVersionRange {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         dispatch_inner!(&self.inner, range => write!(f, "{}", range))
@@ -290,7 +869,10 @@ impl serde::ser::Serialize for DynamicVersionRange {
     where
         S: serde::ser::Serializer,
     {
-        dispatch_inner!(&self.inner, range => range.serialize(serializer))
+        match &self.inner {
+            DynamicVersionRangeInner::Conan(range) => range.serialize(serializer),
+            inner => dispatch_inner!(inner, range => range.serialize(serializer)),
+        }
     }
 }
 
@@ -299,7 +881,15 @@ impl<'de> serde::de::Deserialize<'de> for DynamicVersionRange {
     where
         D: serde::de::Deserializer<'de>,
     {
-        let inner = DynamicVersionRangeInner::deserialize(deserializer)?;
+        let inner = DynamicVersionRangeInner::from(SerializedRange::deserialize(deserializer)?);
+        if let DynamicVersionRangeInner::All(range) | DynamicVersionRangeInner::None(range) = &inner
+            && !matches!(range.constraints.as_slice(), [c] if c.comparator == Comparator::Any)
+        {
+            return Err(serde::de::Error::custom(format!(
+                "the \"{}\" versioning scheme only allows \"*\"",
+                range.versioning_scheme
+            )));
+        }
         Ok(DynamicVersionRange {
             inner,
             cached_constraints: OnceLock::new(),
@@ -369,6 +959,53 @@ mod tests {
         assert_eq!(range.constraints()[0].version.to_string(), "1.0.0+build.1");
     }
 
+    #[test]
+    fn test_dynamic_loose_parsing() {
+        use crate::schemes::semver::Coercion;
+
+        let (range, coercions) =
+            DynamicVersionRange::parse_loose("vers:semver/>=01.0|<v2|!=1.5").unwrap();
+        assert_eq!(range.to_string(), "vers:semver/>=1.0.0|!=1.5.0|<2.0.0");
+        assert_eq!(
+            coercions,
+            [
+                Coercion::StrippedLeadingZeros,
+                Coercion::PaddedComponents,
+                Coercion::StrippedPrefix
+            ]
+        );
+        assert_eq!(
+            range.contains_loose("1.2.3.4".to_string()).unwrap(),
+            (true, vec![Coercion::DroppedComponents])
+        );
+        assert_eq!(
+            range.contains_loose("=1.5".to_string()).unwrap(),
+            (
+                false,
+                vec![Coercion::StrippedPrefix, Coercion::PaddedComponents]
+            )
+        );
+        assert!(range.contains("v1.2".to_string()).is_err());
+
+        let (_, coercions) = DynamicVersionRange::parse_loose("vers:npm/>=1.0.0|<2.0.0").unwrap();
+        assert!(coercions.is_empty());
+
+        // Other schemes are parsed and matched as usual
+        let (range, coercions) = DynamicVersionRange::parse_loose("vers:deb/>=1.0").unwrap();
+        assert!(coercions.is_empty());
+        assert_eq!(
+            range.contains_loose("1.1".to_string()).unwrap(),
+            (true, vec![])
+        );
+        let (range, _) = DynamicVersionRange::parse_loose("vers:hex/>=1.0.0").unwrap();
+        assert!(range.contains_loose("v1.0.0".to_string()).is_err());
+        assert!(DynamicVersionRange::parse_loose("vers:hex/>=1.0").is_err());
+        assert!(matches!(
+            DynamicVersionRange::parse_loose("vers:npm/"),
+            Err(VersError::EmptyConstraints)
+        ));
+    }
+
     #[test]
     fn test_invalid_constraint_simplification() {
         let result: DynamicVersionRange = "vers:npm/1.2.3|<2.0.0".parse().unwrap();
@@ -517,6 +1154,79 @@ mod tests {
         assert_eq!(range.constraints()[0].version.to_string(), "1.0.0");
     }
 
+    #[test]
+    fn test_all_and_none() {
+        let all: DynamicVersionRange = "vers:all/*".parse().unwrap();
+        assert_eq!(all.versioning_scheme(), "all");
+        assert_eq!(all.to_string(), "vers:all/*");
+        assert!(all.contains("not even a version".to_string()).unwrap());
+        assert!(all.matches_all_versions());
+        assert!(!all.matches_no_versions());
+
+        let none: DynamicVersionRange = "vers:none/ * ".parse().unwrap();
+        assert_eq!(none.versioning_scheme(), "none");
+        assert_eq!(none.to_string(), "vers:none/*");
+        assert!(!none.contains("1.0.0".to_string()).unwrap());
+        assert!(!none.matches_all_versions());
+        assert!(none.matches_no_versions());
+
+        assert_eq!(DynamicVersionRange::parse_native("all", "*").unwrap(), all);
+        assert!(matches!(
+            "vers:all/>=1.0".parse::<DynamicVersionRange>(),
+            Err(VersError::InvalidRange(_))
+        ));
+        assert!(matches!(
+            "vers:none/".parse::<DynamicVersionRange>(),
+            Err(VersError::EmptyConstraints)
+        ));
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        for s in [
+            "vers:npm/>=1.0.0|<2.0.0",
+            "vers:semver/*",
+            "vers:deb/>=1:1.0-1",
+            "vers:conda/>=1.2dev|<1.3dev",
+        ] {
+            let range: DynamicVersionRange = s.parse().unwrap();
+            let json = serde_json::to_string(&range).unwrap();
+            let back: DynamicVersionRange = serde_json::from_str(&json).unwrap();
+            assert_eq!(back, range);
+            assert_eq!(back.to_string(), s);
+        }
+    }
+
+    #[test]
+    fn test_all_and_none_serde() {
+        for s in ["vers:all/*", "vers:none/*"] {
+            let range: DynamicVersionRange = s.parse().unwrap();
+            let json = serde_json::to_string(&range).unwrap();
+            let back: DynamicVersionRange = serde_json::from_str(&json).unwrap();
+            assert_eq!(back, range);
+            assert_eq!(back.to_string(), s);
+        }
+
+        let json =
+            r#"{"versioning_scheme":"all","constraints":[{"comparator":"Equal","version":"1.0"}]}"#;
+        assert!(serde_json::from_str::<DynamicVersionRange>(json).is_err());
+    }
+
+    #[test]
+    fn test_to_native_defaults_to_vers_syntax() {
+        let range: DynamicVersionRange = "vers:cran/>=1.2-3|<2.0".parse().unwrap();
+        assert_eq!(range.to_native().unwrap(), ">=1.2-3|<2.0");
+
+        let range: DynamicVersionRange = "vers:npm/*".parse().unwrap();
+        assert_eq!(range.to_native().unwrap(), "*");
+
+        let range: DynamicVersionRange = "vers:all/*".parse().unwrap();
+        assert_eq!(range.to_native().unwrap(), "*");
+
+        let range: DynamicVersionRange = "vers:none/*".parse().unwrap();
+        assert!(matches!(range.to_native(), Err(VersError::InvalidRange(_))));
+    }
+
     #[test]
     fn test_parse_native_roundtrip() {
         let range = DynamicVersionRange::parse_native("npm", ">=1.0.0|<2.0.0").unwrap();
@@ -0,0 +1,503 @@
+//! Interval arithmetic over versions for the vers-rs library.
+//!
+//! Many native range syntaxes combine comparators with "and" / "or" operators
+//! (e.g. Conan's `>=1.0 <2.0 || >3.0`), while a vers range is a flat list of
+//! constraints describing a union of intervals. This module provides the
+//! `IntervalSet` type to evaluate such expressions and convert the result into
+//! a normalized list of vers constraints.
+
+use crate::comparator::Comparator::*;
+use crate::constraint::VersionType;
+use crate::{VersError, VersionConstraint};
+use std::cmp::Ordering;
+
+/// One end of an interval.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bound<V: VersionType> {
+    /// The version at which the interval starts or ends
+    pub version: V,
+    /// Whether `version` itself is part of the interval
+    pub inclusive: bool,
+}
+
+/// A contiguous interval of versions. A missing bound means the interval is
+/// unbounded in that direction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interval<V: VersionType> {
+    /// The lower bound, or `None` if the interval has no lower bound
+    pub lower: Option<Bound<V>>,
+    /// The upper bound, or `None` if the interval has no upper bound
+    pub upper: Option<Bound<V>>,
+}
+
+impl<V: VersionType> Interval<V> {
+    /// Create a new interval from its bounds.
+    pub fn new(lower: Option<Bound<V>>, upper: Option<Bound<V>>) -> Self {
+        Self { lower, upper }
+    }
+
+    /// The interval containing every version.
+    pub fn all() -> Self {
+        Self::new(None, None)
+    }
+
+    /// The interval containing exactly one version.
+    pub fn exactly(version: V) -> Self {
+        Self::new(
+            Some(Bound {
+                version: version.clone(),
+                inclusive: true,
+            }),
+            Some(Bound {
+                version,
+                inclusive: true,
+            }),
+        )
+    }
+
+    /// The interval of versions greater than (or equal to, if `inclusive`) `version`.
+    pub fn above(version: V, inclusive: bool) -> Self {
+        Self::new(Some(Bound { version, inclusive }), None)
+    }
+
+    /// The interval of versions less than (or equal to, if `inclusive`) `version`.
+    pub fn below(version: V, inclusive: bool) -> Self {
+        Self::new(None, Some(Bound { version, inclusive }))
+    }
+
+    /// The half-open interval `[lower, upper)`, the most common shape produced
+    /// by caret, tilde and wildcard operators.
+    pub fn half_open(lower: V, upper: V) -> Self {
+        Self::new(
+            Some(Bound {
+                version: lower,
+                inclusive: true,
+            }),
+            Some(Bound {
+                version: upper,
+                inclusive: false,
+            }),
+        )
+    }
+
+    /// Check whether the interval contains no version at all.
+    pub fn is_empty(&self) -> bool {
+        match (&self.lower, &self.upper) {
+            (Some(lower), Some(upper)) => match lower.version.cmp(&upper.version) {
+                Ordering::Less => false,
+                Ordering::Equal => !(lower.inclusive && upper.inclusive),
+                Ordering::Greater => true,
+            },
+            _ => false,
+        }
+    }
+
+    /// Check whether `version` lies within the interval.
+    pub fn contains(&self, version: &V) -> bool {
+        let above_lower = match &self.lower {
+            None => true,
+            Some(b) if b.inclusive => *version >= b.version,
+            Some(b) => *version > b.version,
+        };
+        let below_upper = match &self.upper {
+            None => true,
+            Some(b) if b.inclusive => *version <= b.version,
+            Some(b) => *version < b.version,
+        };
+        above_lower && below_upper
+    }
+
+    /// Intersect two intervals, returning `None` if they do not overlap.
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let lower = match cmp_lower(&self.lower, &other.lower) {
+            Ordering::Less => other.lower.clone(),
+            _ => self.lower.clone(),
+        };
+        let upper = match cmp_upper(&self.upper, &other.upper) {
+            Ordering::Greater => other.upper.clone(),
+            _ => self.upper.clone(),
+        };
+        let interval = Self::new(lower, upper);
+        (!interval.is_empty()).then_some(interval)
+    }
+
+    fn is_point(&self) -> bool {
+        match (&self.lower, &self.upper) {
+            (Some(lower), Some(upper)) => {
+                lower.inclusive && upper.inclusive && lower.version == upper.version
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Order lower bounds: no bound first, and an inclusive bound before an
+/// exclusive one at the same version.
+fn cmp_lower<V: VersionType>(a: &Option<Bound<V>>, b: &Option<Bound<V>>) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some(a), Some(b)) => a
+            .version
+            .cmp(&b.version)
+            .then_with(|| b.inclusive.cmp(&a.inclusive)),
+    }
+}
+
+/// Order upper bounds: no bound last, and an exclusive bound before an
+/// inclusive one at the same version.
+fn cmp_upper<V: VersionType>(a: &Option<Bound<V>>, b: &Option<Bound<V>>) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => a
+            .version
+            .cmp(&b.version)
+            .then_with(|| a.inclusive.cmp(&b.inclusive)),
+    }
+}
+
+/// A union of disjoint intervals, kept sorted and merged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<V: VersionType> {
+    intervals: Vec<Interval<V>>,
+}
+
+impl<V: VersionType> Default for IntervalSet<V> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<V: VersionType> From<Interval<V>> for IntervalSet<V> {
+    fn from(interval: Interval<V>) -> Self {
+        Self::from_intervals(vec![interval])
+    }
+}
+
+impl<V: VersionType> IntervalSet<V> {
+    /// The set containing no version.
+    pub fn empty() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// The set containing every version.
+    pub fn all() -> Self {
+        Interval::all().into()
+    }
+
+    /// Build a set from arbitrary, possibly overlapping intervals.
+    pub fn from_intervals(intervals: Vec<Interval<V>>) -> Self {
+        let mut set = Self { intervals };
+        set.normalize();
+        set
+    }
+
+    /// Build the set of versions satisfying a single vers constraint.
+    pub fn from_constraint(constraint: &VersionConstraint<V>) -> Self {
+        let version = constraint.version.clone();
+        match constraint.comparator {
+            Any => Self::all(),
+            Equal => Interval::exactly(version).into(),
+            NotEqual => Self::from_intervals(vec![
+                Interval::below(version.clone(), false),
+                Interval::above(version, false),
+            ]),
+            LessThan => Interval::below(version, false).into(),
+            LessThanOrEqual => Interval::below(version, true).into(),
+            GreaterThan => Interval::above(version, false).into(),
+            GreaterThanOrEqual => Interval::above(version, true).into(),
+        }
+    }
+
+    /// Build the set of versions matched by a list of vers constraints.
+    ///
+    /// The list is evaluated like [`VersVersionRange::contains`] does, so it
+    /// should be normalized: `!=` constraints are removed from the set, and
+    /// the remaining range comparators pair up into intervals in order.
+    ///
+    /// [`VersVersionRange::contains`]: crate::range::VersionRange::contains
+    pub fn from_constraints(constraints: &[VersionConstraint<V>]) -> Self {
+        let (excluded, matched): (Vec<_>, Vec<_>) =
+            constraints.iter().partition(|c| c.comparator == NotEqual);
+
+        let mut set = if matched.is_empty() {
+            Self::all()
+        } else {
+            let mut intervals = Vec::new();
+            let mut lower: Option<Bound<V>> = None;
+            let mut first = true;
+            for constraint in matched {
+                let bound = Bound {
+                    version: constraint.version.clone(),
+                    inclusive: matches!(
+                        constraint.comparator,
+                        GreaterThanOrEqual | LessThanOrEqual
+                    ),
+                };
+                match constraint.comparator {
+                    Any => intervals.push(Interval::all()),
+                    Equal => intervals.push(Interval::exactly(bound.version)),
+                    GreaterThan | GreaterThanOrEqual => {
+                        if bound.inclusive {
+                            intervals.push(Interval::exactly(bound.version.clone()));
+                        }
+                        // Only the last of several consecutive lower bounds counts
+                        lower = Some(bound);
+                        first = false;
+                    }
+                    LessThan | LessThanOrEqual => {
+                        if bound.inclusive {
+                            intervals.push(Interval::exactly(bound.version.clone()));
+                        }
+                        // An upper bound without a preceding lower bound only
+                        // counts if it is the first range comparator
+                        if lower.is_some() || first {
+                            intervals.push(Interval::new(lower.take(), Some(bound)));
+                        }
+                        first = false;
+                    }
+                    NotEqual => unreachable!("partitioned out above"),
+                }
+            }
+            if let Some(lower) = lower {
+                intervals.push(Interval::new(Some(lower), None));
+            }
+            Self::from_intervals(intervals)
+        };
+
+        for constraint in excluded {
+            set = set.intersection(&Self::from_constraint(constraint));
+        }
+        set
+    }
+
+    /// The disjoint intervals of this set, in ascending order.
+    pub fn intervals(&self) -> &[Interval<V>] {
+        &self.intervals
+    }
+
+    /// Check whether the set contains no version at all.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Check whether `version` is a member of this set.
+    pub fn contains(&self, version: &V) -> bool {
+        self.intervals.iter().any(|i| i.contains(version))
+    }
+
+    /// The union of two sets ("or").
+    pub fn union(mut self, other: Self) -> Self {
+        self.intervals.extend(other.intervals);
+        self.normalize();
+        self
+    }
+
+    /// The intersection of two sets ("and").
+    pub fn intersection(&self, other: &Self) -> Self {
+        let intervals = self
+            .intervals
+            .iter()
+            .flat_map(|a| other.intervals.iter().filter_map(|b| a.intersect(b)))
+            .collect();
+        Self::from_intervals(intervals)
+    }
+
+    /// Convert the set into a normalized list of vers constraints.
+    ///
+    /// Single-version gaps between two intervals are expressed with a `!=`
+    /// constraint instead of a `<` / `>` pair, since a vers range must not
+    /// mention the same version twice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VersError::InvalidRange`] if the set is empty, since a vers
+    /// range cannot express a range matching no version.
+    pub fn to_constraints(&self) -> Result<Vec<VersionConstraint<V>>, VersError> {
+        if self.intervals.is_empty() {
+            return Err(VersError::InvalidRange(
+                "range does not match any version".to_string(),
+            ));
+        }
+
+        if self.intervals.len() == 1
+            && self.intervals[0].lower.is_none()
+            && self.intervals[0].upper.is_none()
+        {
+            return Ok(vec![VersionConstraint::new(Any, V::default())]);
+        }
+
+        let mut constraints: Vec<VersionConstraint<V>> = Vec::new();
+        let mut previous_upper: Option<&Bound<V>> = None;
+
+        for interval in &self.intervals {
+            if interval.is_point() {
+                let version = interval.lower.as_ref().unwrap().version.clone();
+                constraints.push(VersionConstraint::new(Equal, version));
+                previous_upper = None;
+                continue;
+            }
+
+            if let Some(lower) = &interval.lower {
+                match previous_upper {
+                    // A gap of exactly one version: turn the preceding "<v" into "!=v"
+                    Some(upper)
+                        if !upper.inclusive
+                            && !lower.inclusive
+                            && upper.version == lower.version =>
+                    {
+                        constraints.last_mut().unwrap().comparator = NotEqual;
+                    }
+                    _ => {
+                        let comparator = if lower.inclusive {
+                            GreaterThanOrEqual
+                        } else {
+                            GreaterThan
+                        };
+                        constraints.push(VersionConstraint::new(comparator, lower.version.clone()));
+                    }
+                }
+            }
+
+            if let Some(upper) = &interval.upper {
+                let comparator = if upper.inclusive {
+                    LessThanOrEqual
+                } else {
+                    LessThan
+                };
+                constraints.push(VersionConstraint::new(comparator, upper.version.clone()));
+            }
+            previous_upper = interval.upper.as_ref();
+        }
+
+        Ok(constraints)
+    }
+
+    /// Sort the intervals by lower bound and merge overlapping or touching ones.
+    fn normalize(&mut self) {
+        self.intervals.retain(|i| !i.is_empty());
+        self.intervals.sort_by(|a, b| cmp_lower(&a.lower, &b.lower));
+
+        let mut merged: Vec<Interval<V>> = Vec::with_capacity(self.intervals.len());
+        for interval in self.intervals.drain(..) {
+            if let Some(last) = merged.last_mut() {
+                let touches = match (&last.upper, &interval.lower) {
+                    (None, _) | (_, None) => true,
+                    (Some(upper), Some(lower)) => match upper.version.cmp(&lower.version) {
+                        Ordering::Greater => true,
+                        Ordering::Equal => upper.inclusive || lower.inclusive,
+                        Ordering::Less => false,
+                    },
+                };
+                if touches {
+                    if cmp_upper(&interval.upper, &last.upper) == Ordering::Greater {
+                        last.upper = interval.upper;
+                    }
+                    continue;
+                }
+            }
+            merged.push(interval);
+        }
+        self.intervals = merged;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Interval, IntervalSet};
+    use crate::range::VersionRange;
+    use crate::schemes::semver::SemVer;
+    use crate::{VersError, VersVersionRange};
+
+    #[test]
+    fn test_union_at_shared_bounds() {
+        let one: SemVer = "1.0.0".parse().unwrap();
+        let two: SemVer = "2.0.0".parse().unwrap();
+        let three: SemVer = "3.0.0".parse().unwrap();
+
+        // Touching intervals merge as long as one of them includes the bound
+        let set = IntervalSet::from(Interval::half_open(one.clone(), two.clone()))
+            .union(Interval::half_open(two.clone(), three.clone()).into());
+        assert_eq!(
+            set.intervals(),
+            [Interval::half_open(one.clone(), three.clone())]
+        );
+
+        // Otherwise the single version between them becomes a `!=`
+        let lower = Interval::above(one.clone(), false)
+            .intersect(&Interval::below(two.clone(), false))
+            .unwrap();
+        let upper = Interval::above(two.clone(), false)
+            .intersect(&Interval::below(three.clone(), false))
+            .unwrap();
+        let set = IntervalSet::from_intervals(vec![upper, lower]);
+        assert_eq!(set.intervals().len(), 2);
+        assert!(!set.contains(&two));
+        let range = VersVersionRange::new("semver".to_string(), set.to_constraints().unwrap());
+        assert_eq!(range.to_string(), "vers:semver/>1.0.0|!=2.0.0|<3.0.0");
+
+        // Complementary half-lines cover everything, or all but one version
+        let set = IntervalSet::from(Interval::below(two.clone(), false))
+            .union(Interval::above(two.clone(), true).into());
+        assert_eq!(set, IntervalSet::all());
+        let set = IntervalSet::from(Interval::below(two.clone(), false))
+            .union(Interval::above(two, false).into());
+        let range = VersVersionRange::new("semver".to_string(), set.to_constraints().unwrap());
+        assert_eq!(range.to_string(), "vers:semver/!=2.0.0");
+    }
+
+    #[test]
+    fn test_intersection_at_shared_bounds() {
+        let one: SemVer = "1.0.0".parse().unwrap();
+        let two: SemVer = "2.0.0".parse().unwrap();
+        let three: SemVer = "3.0.0".parse().unwrap();
+
+        // Closed intervals sharing a bound meet in a single version
+        let set = IntervalSet::from(Interval::below(two.clone(), true))
+            .intersection(&Interval::above(two.clone(), true).into());
+        assert_eq!(set, Interval::exactly(two.clone()).into());
+        let range = VersVersionRange::new("semver".to_string(), set.to_constraints().unwrap());
+        assert_eq!(range.to_string(), "vers:semver/2.0.0");
+
+        // A half-open interval does not, and an empty set has no constraints
+        let set = IntervalSet::from(Interval::half_open(one, two.clone()))
+            .intersection(&Interval::half_open(two, three).into());
+        assert!(set.is_empty());
+        assert!(matches!(
+            set.to_constraints(),
+            Err(VersError::InvalidRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_constraints_matches_contains() {
+        for raw in [
+            "vers:semver/*",
+            "vers:semver/1.0.0|1.1.0",
+            "vers:semver/<1.0.0|>=1.2.0|!=1.3.0|<=2.0.0|>3.0.0",
+            "vers:semver/!=1.0.0|!=1.1.0",
+            "vers:semver/>1.0.0|<1.0.5|1.2.0",
+        ] {
+            let range: VersVersionRange<SemVer> = raw.parse().unwrap();
+            let set = IntervalSet::from_constraints(&range.constraints);
+            let rendered =
+                VersVersionRange::new("semver".to_string(), set.to_constraints().unwrap());
+            assert_eq!(rendered.to_string(), raw);
+            for version in [
+                "0.1.0", "1.0.0", "1.0.3", "1.1.0", "1.2.0", "1.3.0", "2.0.0",
+            ] {
+                let version: SemVer = version.parse().unwrap();
+                assert_eq!(
+                    set.contains(&version),
+                    range.contains(version.clone()).unwrap(),
+                    "{raw} {version}"
+                );
+            }
+        }
+    }
+}
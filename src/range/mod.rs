@@ -8,4 +8,5 @@ pub trait VersionRange<V: VersionType> {
 }
 
 pub mod dynamic;
+pub mod interval;
 pub mod vers;
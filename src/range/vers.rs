@@ -104,10 +104,8 @@ impl<V: VersionType> VersionRange<V> for VersVersionRange<V> {
         // Check for exact matches with equality and inequality comparators
         for constraint in &self.constraints {
             match constraint.comparator {
-                Equal | GreaterThanOrEqual | LessThanOrEqual => {
-                    if version == constraint.version {
-                        return Ok(true);
-                    }
+                Equal | GreaterThanOrEqual | LessThanOrEqual if version == constraint.version => {
+                    return Ok(true);
                 }
                 NotEqual if version == constraint.version => {
                     return Ok(false);
@@ -378,6 +376,26 @@ impl<V: VersionType> VersVersionRange<V> {
     }
 }
 
+impl<V: NativeVersionConverter> VersVersionRange<V> {
+    /// Render this range in the native syntax of its versioning scheme.
+    ///
+    /// See [`NativeVersionConverter::to_native`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::VersVersionRange;
+    /// use vers_rs::schemes::deb::DebVersion;
+    ///
+    /// let range: VersVersionRange<DebVersion> = "vers:deb/>=1.0|<<2.0".parse().unwrap();
+    /// assert_eq!(range.to_string(), "vers:deb/>=1.0|<2.0");
+    /// assert_eq!(range.to_native().unwrap(), ">=1.0|<<2.0");
+    /// ```
+    pub fn to_native(&self) -> Result<String, VersError> {
+        V::to_native(self)
+    }
+}
+
 impl<V: NativeVersionConverter> FromStr for VersVersionRange<V> {
     type Err = VersError;
 
@@ -0,0 +1,377 @@
+use crate::comparator::Comparator;
+use crate::constraint::NativeVersionConverter;
+use crate::range::interval::{Interval, IntervalSet};
+use crate::{VersError, VersVersionRange, VersionConstraint};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+/// Scheme identifier string for CocoaPods versions
+pub const COCOAPODS_SCHEME: &str = "cocoapods";
+
+/// Macro to create InvalidVersionFormat errors for CocoaPods versions
+macro_rules! cocoapods_format_error {
+    ($s:expr, $msg:expr) => {
+        VersError::InvalidVersionFormat(COCOAPODS_SCHEME.to_string(), $s.to_string(), $msg.into())
+    };
+}
+
+/// A segment of a CocoaPods version: a run of digits or a run of letters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Segment {
+    Number(u64),
+    Text(String),
+}
+
+/// CocoaPods version, following the `Gem::Version` model it is built on:
+///
+/// - The version is split into runs of digits and runs of letters, so
+///   `1.0b2` has the segments `1`, `0`, `b`, `2`
+/// - A `-` starts a pre-release and is read as `.pre.`, so `1.0.0-beta.1`
+///   sorts like `1.0.0.pre.beta.1`
+/// - Any letter segment marks a pre-release, which sorts before numbers
+/// - Trailing zeros are ignored, so `1.0 == 1.0.0`
+///
+/// The original string is preserved for display.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CocoaPodsVersion {
+    value: String,
+    segments: Vec<Segment>,
+}
+
+impl CocoaPodsVersion {
+    /// Check whether this version is a pre-release, i.e. contains letters.
+    pub fn is_prerelease(&self) -> bool {
+        self.segments.iter().any(|s| matches!(s, Segment::Text(_)))
+    }
+
+    /// The segments used for comparison: trailing zeros are dropped from both
+    /// the release part and the pre-release part.
+    fn canonical_segments<'a>(&'a self) -> Vec<&'a Segment> {
+        let split = self
+            .segments
+            .iter()
+            .position(|s| matches!(s, Segment::Text(_)))
+            .unwrap_or(self.segments.len());
+        let (release, pre_release) = self.segments.split_at(split);
+        let trim = |part: &'a [Segment]| {
+            let end = part
+                .iter()
+                .rposition(|s| *s != Segment::Number(0))
+                .map_or(0, |i| i + 1);
+            &part[..end]
+        };
+        trim(release).iter().chain(trim(pre_release)).collect()
+    }
+
+    /// The upper bound of the optimistic operator: drop the pre-release part
+    /// and the last release segment, then increment the new last segment
+    /// (`1.2.3` → `1.3`, `1.2` → `2`).
+    ///
+    /// Returns `None` for single-segment versions. CocoaPods documents
+    /// `~> 0` as "version 0 and higher", so there is no upper bound, whereas
+    /// RubyGems would bump to `1`.
+    fn bump(&self) -> Option<Self> {
+        let mut release: Vec<u64> = self
+            .segments
+            .iter()
+            .map_while(|s| match s {
+                Segment::Number(n) => Some(*n),
+                Segment::Text(_) => None,
+            })
+            .collect();
+        if release.len() < 2 {
+            return None;
+        }
+        release.pop();
+        *release.last_mut().unwrap() += 1;
+        let value = release
+            .iter()
+            .map(u64::to_string)
+            .collect::<Vec<_>>()
+            .join(".");
+        Some(CocoaPodsVersion {
+            value,
+            segments: release.into_iter().map(Segment::Number).collect(),
+        })
+    }
+}
+
+impl Default for CocoaPodsVersion {
+    fn default() -> Self {
+        CocoaPodsVersion {
+            value: "0".to_string(),
+            segments: vec![Segment::Number(0)],
+        }
+    }
+}
+
+impl fmt::Display for CocoaPodsVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl FromStr for CocoaPodsVersion {
+    type Err = VersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(cocoapods_format_error!(s, "empty"));
+        }
+
+        let (release, pre_release) = match s.split_once('-') {
+            Some((release, pre_release)) => (release, Some(pre_release)),
+            None => (s, None),
+        };
+
+        if !release.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(cocoapods_format_error!(
+                s,
+                "version must start with a digit"
+            ));
+        }
+        if release
+            .split('.')
+            .any(|part| part.is_empty() || !part.chars().all(|c| c.is_ascii_alphanumeric()))
+        {
+            return Err(cocoapods_format_error!(s, "invalid release segment"));
+        }
+        if let Some(pre_release) = pre_release
+            && pre_release.split('.').any(|part| {
+                part.is_empty() || !part.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            })
+        {
+            return Err(cocoapods_format_error!(s, "invalid pre-release segment"));
+        }
+
+        let normalized = s.replace('-', ".pre.");
+        let mut segments = Vec::new();
+        let mut rest = normalized.as_str();
+        while let Some(c) = rest.chars().next() {
+            let is_digit = c.is_ascii_digit();
+            let end = rest
+                .find(|c: char| !c.is_ascii_alphanumeric() || c.is_ascii_digit() != is_digit)
+                .unwrap_or(rest.len());
+            let (run, remainder) = rest.split_at(end);
+            if is_digit {
+                let n = run
+                    .parse::<u64>()
+                    .map_err(|e| cocoapods_format_error!(s, format!("invalid number: {e}")))?;
+                segments.push(Segment::Number(n));
+            } else if !run.is_empty() {
+                segments.push(Segment::Text(run.to_string()));
+            }
+            rest = remainder.trim_start_matches('.');
+        }
+
+        Ok(CocoaPodsVersion {
+            value: s.to_string(),
+            segments,
+        })
+    }
+}
+
+impl Ord for CocoaPodsVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let lhs = self.canonical_segments();
+        let rhs = other.canonical_segments();
+        let zero = Segment::Number(0);
+
+        for i in 0..lhs.len().max(rhs.len()) {
+            let a = lhs.get(i).copied().unwrap_or(&zero);
+            let b = rhs.get(i).copied().unwrap_or(&zero);
+            let ord = match (a, b) {
+                (Segment::Number(a), Segment::Number(b)) => a.cmp(b),
+                (Segment::Text(a), Segment::Text(b)) => a.cmp(b),
+                (Segment::Text(_), Segment::Number(_)) => Ordering::Less,
+                (Segment::Number(_), Segment::Text(_)) => Ordering::Greater,
+            };
+            if ord != Ordering::Equal {
+                return ord;
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+impl PartialOrd for CocoaPodsVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Equality follows the ordering, so that `1.0` equals `1.0.0`
+impl PartialEq for CocoaPodsVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for CocoaPodsVersion {}
+
+impl TryFrom<String> for CocoaPodsVersion {
+    type Error = VersError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<CocoaPodsVersion> for String {
+    fn from(v: CocoaPodsVersion) -> Self {
+        v.value
+    }
+}
+
+impl NativeVersionConverter for CocoaPodsVersion {
+    const SCHEME_NAME: &'static str = COCOAPODS_SCHEME;
+
+    /// Parse a Podfile requirement like `~> 0.1.2` or `>= 1.0, != 1.5, < 2.0`.
+    ///
+    /// Requirements are separated by commas and must all hold. Supported
+    /// operators are `=` (the default), `!=`, `>`, `>=`, `<`, `<=` and the
+    /// optimistic operator `~>`:
+    /// - `~> 0.1.2` means `>= 0.1.2` and `< 0.2`
+    /// - `~> 0.1` means `>= 0.1` and `< 1`
+    /// - `~> 0` means `>= 0`, as documented by CocoaPods
+    fn from_native_string(scheme: &str, raw: &str) -> Result<VersVersionRange<Self>, VersError> {
+        let mut set = IntervalSet::all();
+        for requirement in raw.split(',') {
+            set = set.intersection(&parse_requirement(requirement.trim())?);
+        }
+
+        let mut range = VersVersionRange::new(scheme.to_string(), set.to_constraints()?);
+        range.normalize_and_validate()?;
+        Ok(range)
+    }
+}
+
+/// Parse a single Podfile requirement into the set of matching versions.
+fn parse_requirement(requirement: &str) -> Result<IntervalSet<CocoaPodsVersion>, VersError> {
+    let (operator, version) = ["~>", "!=", ">=", "<=", ">", "<", "="]
+        .iter()
+        .find_map(|op| {
+            requirement
+                .strip_prefix(op)
+                .map(|version| (*op, version.trim()))
+        })
+        .unwrap_or(("=", requirement));
+
+    if version.is_empty() {
+        return Err(VersError::InvalidConstraint(format!(
+            "missing version in CocoaPods requirement '{}'",
+            requirement
+        )));
+    }
+    let version: CocoaPodsVersion = version.parse()?;
+
+    let comparator = match operator {
+        "~>" => {
+            return Ok(match version.bump() {
+                Some(upper) => Interval::half_open(version, upper),
+                None => Interval::above(version, true),
+            }
+            .into());
+        }
+        "!=" => Comparator::NotEqual,
+        ">=" => Comparator::GreaterThanOrEqual,
+        "<=" => Comparator::LessThanOrEqual,
+        ">" => Comparator::GreaterThan,
+        "<" => Comparator::LessThan,
+        _ => Comparator::Equal,
+    };
+    Ok(IntervalSet::from_constraint(&VersionConstraint::new(
+        comparator, version,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CocoaPodsVersion;
+    use crate::range::VersionRange;
+    use crate::range::dynamic::DynamicVersionRange;
+
+    #[test]
+    fn test_cocoapods_version_ordering() {
+        // Letter segments sort before numbers, wherever they appear
+        let versions: Vec<CocoaPodsVersion> = [
+            "1.0.a",
+            "1.0b2",
+            "1.0.0-beta.1",
+            "1.0.0-beta.2",
+            "1.0.0-rc.1",
+            "1.0",
+            "1.0.0.1",
+            "1.9",
+            "1.10",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+        for pair in versions.windows(2) {
+            assert!(pair[0] < pair[1], "{} < {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_cocoapods_equivalent_spellings() {
+        // Trailing zeros of the release and the pre-release are ignored, a
+        // `-` reads as `.pre.` and letters split from digits
+        for (a, b) in [
+            ("1.0", "1.0.0"),
+            ("1.0.0-beta.0", "1.0-beta"),
+            ("1.0.0-beta", "1.0.0.pre.beta"),
+            ("2.0rc1", "2.0.rc.1"),
+        ] {
+            let (va, vb): (CocoaPodsVersion, CocoaPodsVersion) =
+                (a.parse().unwrap(), b.parse().unwrap());
+            assert_eq!(va, vb, "{a} == {b}");
+            assert_eq!(va.to_string(), a);
+        }
+    }
+
+    #[test]
+    fn test_cocoapods_optimistic_operator() {
+        for (raw, expected) in [
+            ("~> 0.1.2", "vers:cocoapods/>=0.1.2|<0.2"),
+            ("~> 0.1", "vers:cocoapods/>=0.1|<1"),
+            // CocoaPods documents `~> 0` as "0 and higher"
+            ("~> 0", "vers:cocoapods/>=0"),
+            // The pre-release part is dropped before bumping
+            ("~> 1.0.0-beta.1", "vers:cocoapods/>=1.0.0-beta.1|<1.1"),
+            ("~> 1.2, >= 1.2.5", "vers:cocoapods/>=1.2.5|<2"),
+        ] {
+            let range = DynamicVersionRange::parse_native("cocoapods", raw).unwrap();
+            assert_eq!(range.to_string(), expected, "{raw}");
+        }
+
+        // As in RubyGems, pre-releases of the bumped version sort below it
+        // and therefore match
+        let range = DynamicVersionRange::parse_native("cocoapods", "~> 1.2").unwrap();
+        assert!(range.contains("1.9.9".to_string()).unwrap());
+        assert!(range.contains("2.0.beta".to_string()).unwrap());
+        assert!(!range.contains("2.0".to_string()).unwrap());
+    }
+
+    #[test]
+    fn test_cocoapods_invalid_requirements() {
+        for raw in [
+            "",
+            "~>",
+            ">= 1.0, ",
+            "> 2.0, < 1.0",
+            ">= a1.0",
+            "1..0",
+            "1.0-beta..1",
+            "1.0_1",
+        ] {
+            assert!(
+                DynamicVersionRange::parse_native("cocoapods", raw).is_err(),
+                "{raw}"
+            );
+        }
+    }
+}
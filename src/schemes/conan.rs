@@ -0,0 +1,604 @@
+use crate::constraint::NativeVersionConverter;
+use crate::range::VersionRange;
+use crate::range::interval::{Interval, IntervalSet};
+use crate::{VersError, VersVersionRange};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+/// Scheme identifier string for Conan versions
+pub const CONAN_SCHEME: &str = "conan";
+
+/// Macro to create InvalidVersionFormat errors for Conan versions
+macro_rules! conan_format_error {
+    ($s:expr, $msg:expr) => {
+        VersError::InvalidVersionFormat(CONAN_SCHEME.to_string(), $s.to_string(), $msg.into())
+    };
+}
+
+/// Characters that are part of the Conan range syntax and can therefore not
+/// appear in a version.
+const RESERVED_CHARS: &[char] = &['[', ']', '|', ',', '<', '>', '=', '~', '^', '*'];
+
+/// A single dot-separated item of a Conan version.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum ConanVersionItem {
+    Number(u64),
+    Text(String),
+}
+
+impl ConanVersionItem {
+    fn parse(item: &str) -> Self {
+        match item.parse::<u64>() {
+            Ok(n) if item.chars().all(|c| c.is_ascii_digit()) => ConanVersionItem::Number(n),
+            _ => ConanVersionItem::Text(item.to_string()),
+        }
+    }
+}
+
+impl ConanVersionItem {
+    /// Rank of the item kind. Conan compares the string representations when
+    /// a number meets a non-numeric item, which is not transitive (`2 < 10`,
+    /// `10 < 1a` and `1a < 2`). Sort numbers before text instead, keeping the
+    /// empty item first so that the empty pre-release of `1.0-` still sorts
+    /// before every other pre-release.
+    fn rank(&self) -> u8 {
+        match self {
+            ConanVersionItem::Text(t) if t.is_empty() => 0,
+            ConanVersionItem::Number(_) => 1,
+            ConanVersionItem::Text(_) => 2,
+        }
+    }
+}
+
+impl Ord for ConanVersionItem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (ConanVersionItem::Number(a), ConanVersionItem::Number(b)) => a.cmp(b),
+            (ConanVersionItem::Text(a), ConanVersionItem::Text(b)) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl PartialOrd for ConanVersionItem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Strip trailing zero items, so that `1.2` and `1.2.0` compare equal.
+fn non_zero_items(items: &[ConanVersionItem]) -> &[ConanVersionItem] {
+    let end = items
+        .iter()
+        .rposition(|item| *item != ConanVersionItem::Number(0))
+        .map_or(0, |i| i + 1);
+    &items[..end]
+}
+
+fn parse_items(s: &str) -> Vec<ConanVersionItem> {
+    s.split('.').map(ConanVersionItem::parse).collect()
+}
+
+/// Conan 2 version: `main[-pre][+build]`.
+///
+/// This implementation follows the Conan 2 `Version` model:
+/// - The main part, the pre-release and the build are dot-separated lists of
+///   items of arbitrary length; numeric items compare numerically, other items
+///   as strings, and numeric items sort before other non-empty items
+/// - Trailing zero items are ignored, so `1.2 == 1.2.0`
+/// - A pre-release (after the first `-`) sorts before the plain version
+/// - A build (after the last `+`) sorts after the plain version
+///
+/// The original string is preserved for display.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ConanVersion {
+    value: String,
+    items: Vec<ConanVersionItem>,
+    pre_release: Option<Vec<ConanVersionItem>>,
+    build: Option<Vec<ConanVersionItem>>,
+}
+
+impl ConanVersion {
+    /// Check whether this version carries a pre-release part.
+    pub fn is_prerelease(&self) -> bool {
+        self.pre_release.is_some()
+    }
+
+    /// Whether the version has neither a pre-release nor a build part.
+    fn is_plain(&self) -> bool {
+        self.pre_release.is_none() && self.build.is_none()
+    }
+
+    /// Compute Conan's `upper_bound(index)`: keep the first `index` items,
+    /// increment the item at `index` and exclude all its pre-releases.
+    fn upper_bound(&self, index: usize) -> Result<Self, VersError> {
+        let bumped = match self.items.get(index) {
+            Some(ConanVersionItem::Number(n)) => n + 1,
+            _ => {
+                return Err(VersError::InvalidConstraint(format!(
+                    "cannot bump item {} of version '{}', it is not a number",
+                    index, self.value
+                )));
+            }
+        };
+        let mut bound: Vec<String> = self.items[..index]
+            .iter()
+            .map(|item| match item {
+                ConanVersionItem::Number(n) => n.to_string(),
+                ConanVersionItem::Text(t) => t.clone(),
+            })
+            .collect();
+        bound.push(bumped.to_string());
+        format!("{}-", bound.join(".")).parse()
+    }
+
+    /// Append an empty pre-release (`1.0` → `1.0-`), which sorts before every
+    /// pre-release of that version. Conan does this for the `>=` and `<`
+    /// operators so that `<2.0` excludes `2.0-beta`.
+    fn with_min_prerelease(self) -> Result<Self, VersError> {
+        if self.is_plain() {
+            format!("{}-", self.value).parse()
+        } else {
+            Ok(self)
+        }
+    }
+}
+
+impl Default for ConanVersion {
+    fn default() -> Self {
+        ConanVersion {
+            value: "0".to_string(),
+            items: vec![ConanVersionItem::Number(0)],
+            pre_release: None,
+            build: None,
+        }
+    }
+}
+
+impl fmt::Display for ConanVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl FromStr for ConanVersion {
+    type Err = VersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(conan_format_error!(s, "empty"));
+        }
+        if let Some(c) = s
+            .chars()
+            .find(|c| c.is_whitespace() || RESERVED_CHARS.contains(c))
+        {
+            return Err(conan_format_error!(s, format!("invalid character '{c}'")));
+        }
+
+        let (rest, build) = match s.rsplit_once('+') {
+            Some((rest, build)) => (rest, Some(parse_items(build))),
+            None => (s, None),
+        };
+        let (main, pre_release) = match rest.split_once('-') {
+            Some((main, pre)) => (main, Some(parse_items(pre))),
+            None => (rest, None),
+        };
+        if main.is_empty() {
+            return Err(conan_format_error!(s, "missing main version"));
+        }
+
+        Ok(ConanVersion {
+            value: s.to_string(),
+            items: parse_items(main),
+            pre_release,
+            build,
+        })
+    }
+}
+
+impl Ord for ConanVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let compare_build = || match (&self.build, &other.build) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+            (Some(a), Some(b)) => non_zero_items(a).cmp(non_zero_items(b)),
+        };
+
+        non_zero_items(&self.items)
+            .cmp(non_zero_items(&other.items))
+            .then_with(|| match (&self.pre_release, &other.pre_release) {
+                (None, None) => compare_build(),
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) => non_zero_items(a)
+                    .cmp(non_zero_items(b))
+                    .then_with(compare_build),
+            })
+    }
+}
+
+impl PartialOrd for ConanVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Equality follows the ordering, so that `1.2` equals `1.2.0`
+impl PartialEq for ConanVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for ConanVersion {}
+
+impl TryFrom<String> for ConanVersion {
+    type Error = VersError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<ConanVersion> for String {
+    fn from(v: ConanVersion) -> Self {
+        v.value
+    }
+}
+
+impl NativeVersionConverter for ConanVersion {
+    const SCHEME_NAME: &'static str = CONAN_SCHEME;
+
+    /// Parse a Conan version range expression like `[>=1.0 <2.0 || ^3.1, include_prerelease]`.
+    ///
+    /// See [`ConanVersionRange`] for the supported syntax. The
+    /// `include_prerelease` option is accepted but not represented in the
+    /// returned range; use [`ConanVersionRange`] to honour it.
+    /// [`DynamicVersionRange::parse_native`](crate::DynamicVersionRange::parse_native)
+    /// keeps the option.
+    fn from_native_string(scheme: &str, raw: &str) -> Result<VersVersionRange<Self>, VersError> {
+        Ok(ConanVersionRange::from_native_string(scheme, raw)?.range)
+    }
+}
+
+/// A Conan version range together with its options.
+///
+/// Conan version ranges are written as `[expression, option, ...]`:
+/// - Alternatives are separated by `||`, the conditions of an alternative by spaces
+/// - Conditions use `>`, `>=`, `<`, `<=` or `=` (the default), or the
+///   shorthands `~1.2` (`>=1.2 <1.3-`) and `^1.2` (`>=1.2 <2-`)
+/// - An empty expression or `*` matches any version
+/// - The `include_prerelease` option allows pre-release versions to match
+///
+/// As in Conan, `>=` and `<` bounds without a pre-release get an empty one
+/// appended (`<2.0` becomes `<2.0-`), so that pre-releases of the upper bound
+/// are excluded.
+///
+/// A vers range has no notion of Conan's pre-release filter, so the option is
+/// kept alongside the range and applied by [`ConanVersionRange::contains`].
+/// It is serialized next to the fields of the vers range, but lost in the
+/// vers string of the range.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConanVersionRange {
+    /// The vers range describing the matching versions
+    #[serde(flatten)]
+    pub range: VersVersionRange<ConanVersion>,
+    /// Whether pre-release versions may match the range
+    pub include_prerelease: bool,
+}
+
+impl ConanVersionRange {
+    /// Parse a native Conan version range, labelling the resulting vers range
+    /// with the given `scheme`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::schemes::conan::ConanVersionRange;
+    ///
+    /// let range = ConanVersionRange::from_native_string("conan", "[~1.2, include_prerelease]").unwrap();
+    /// assert_eq!(range.range.to_string(), "vers:conan/>=1.2-|<1.3-");
+    /// assert!(range.include_prerelease);
+    /// ```
+    pub fn from_native_string(scheme: &str, raw: &str) -> Result<Self, VersError> {
+        let raw = raw.trim();
+        let raw = match raw.strip_prefix('[') {
+            Some(inner) => inner.strip_suffix(']').ok_or_else(|| {
+                VersError::InvalidConstraint(format!("unterminated Conan range '{}'", raw))
+            })?,
+            None => raw,
+        };
+
+        let mut tokens = raw.split(',');
+        let expression = tokens.next().unwrap_or_default();
+
+        let mut include_prerelease = false;
+        for option in tokens.map(str::trim).filter(|t| !t.is_empty()) {
+            match option {
+                "include_prerelease" | "include_prerelease=True" => include_prerelease = true,
+                _ => {
+                    return Err(VersError::InvalidConstraint(format!(
+                        "unsupported Conan range option '{}'",
+                        option
+                    )));
+                }
+            }
+        }
+
+        let mut set = IntervalSet::empty();
+        for alternative in expression.split("||") {
+            let mut conditions = IntervalSet::all();
+            for condition in alternative.split_whitespace() {
+                conditions = conditions.intersection(&parse_condition(condition)?);
+            }
+            set = set.union(conditions);
+        }
+
+        let mut range = VersVersionRange::new(scheme.to_string(), set.to_constraints()?);
+        range.normalize_and_validate()?;
+
+        Ok(ConanVersionRange {
+            range,
+            include_prerelease,
+        })
+    }
+
+    /// Check if a version is contained within this range, honouring the
+    /// `include_prerelease` option.
+    pub fn contains(&self, version: &ConanVersion) -> Result<bool, VersError> {
+        if version.is_prerelease() && !self.include_prerelease {
+            return Ok(false);
+        }
+        self.range.contains(version.clone())
+    }
+}
+
+/// A range without Conan options, such as one parsed from a `vers:conan`
+/// string. As in a native range without `include_prerelease`, pre-release
+/// versions do not match.
+impl From<VersVersionRange<ConanVersion>> for ConanVersionRange {
+    fn from(range: VersVersionRange<ConanVersion>) -> Self {
+        ConanVersionRange {
+            range,
+            include_prerelease: false,
+        }
+    }
+}
+
+/// Parse a single Conan range condition like `>=1.0`, `~1.2` or `^1.2`.
+fn parse_condition(condition: &str) -> Result<IntervalSet<ConanVersion>, VersError> {
+    if condition == "*" {
+        return Ok(IntervalSet::all());
+    }
+
+    let (operator, version) = [">=", "<=", ">", "<", "=", "~", "^"]
+        .iter()
+        .find_map(|op| condition.strip_prefix(op).map(|v| (*op, v)))
+        .unwrap_or(("=", condition));
+
+    if version.is_empty() || version.starts_with('=') {
+        return Err(VersError::InvalidConstraint(format!(
+            "invalid Conan range condition '{}'",
+            condition
+        )));
+    }
+
+    let version: ConanVersion = version.parse()?;
+
+    let interval = match operator {
+        ">=" => Interval::above(version.with_min_prerelease()?, true),
+        ">" => Interval::above(version, false),
+        "<" => Interval::below(version.with_min_prerelease()?, false),
+        "<=" => Interval::below(version, true),
+        "~" => {
+            let index = if version.items.len() > 1 { 1 } else { 0 };
+            let upper = version.upper_bound(index)?;
+            Interval::half_open(version.with_min_prerelease()?, upper)
+        }
+        "^" => {
+            let index = version
+                .items
+                .iter()
+                .position(|item| *item != ConanVersionItem::Number(0))
+                .ok_or_else(|| {
+                    VersError::InvalidConstraint(format!(
+                        "caret range '{}' needs a non-zero item",
+                        condition
+                    ))
+                })?;
+            let upper = version.upper_bound(index)?;
+            Interval::half_open(version.with_min_prerelease()?, upper)
+        }
+        _ => Interval::exactly(version),
+    };
+
+    Ok(interval.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ConanVersion, ConanVersionRange};
+    use crate::VersError;
+    use crate::range::VersionRange;
+    use crate::range::dynamic::DynamicVersionRange;
+
+    #[test]
+    fn test_conan_version_ordering() {
+        // Items of arbitrary count, numbers before text, pre-releases before
+        // the plain version and builds after it
+        let versions: Vec<ConanVersion> = [
+            "1.0-",
+            "1.0-1",
+            "1.0-alpha",
+            "1.0-alpha.2",
+            "1.0-beta",
+            "1.0",
+            "1.0+1",
+            "1.0+build1",
+            "1.2",
+            "1.2.3.4.5",
+            "1.2.3.4.6",
+            "1.10",
+            "1.1a",
+            "1.a",
+            "1.b",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+        for pair in versions.windows(2) {
+            assert!(pair[0] < pair[1], "{} < {}", pair[0], pair[1]);
+        }
+
+        // The order is total even where Conan compares mixed items as
+        // strings (`2 < 10`, `10 < 1a`, `1a < 2`)
+        let mut shuffled = versions.clone();
+        shuffled.reverse();
+        shuffled.sort();
+        assert_eq!(shuffled, versions);
+    }
+
+    #[test]
+    fn test_conan_trailing_zero_items() {
+        for (a, b) in [
+            ("1.2", "1.2.0.0"),
+            ("1.0-beta", "1.0-beta.0"),
+            ("1.0+b1", "1.0+b1.0"),
+        ] {
+            let (va, vb): (ConanVersion, ConanVersion) = (a.parse().unwrap(), b.parse().unwrap());
+            assert_eq!(va, vb, "{a} == {b}");
+            // The original string is kept for display
+            assert_eq!(va.to_string(), a);
+            assert_eq!(vb.to_string(), b);
+        }
+
+        for s in ["", "-beta", "+b1", "1.0 beta", "1.0|2.0", "[1.0]", "1.*"] {
+            assert!(
+                matches!(
+                    s.parse::<ConanVersion>(),
+                    Err(VersError::InvalidVersionFormat(..))
+                ),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_conan_native_ranges() {
+        for (raw, expected) in [
+            // `>=` and `<` exclude the pre-releases of their bound
+            ("[>=1.0 <2.0]", "vers:conan/>=1.0-|<2.0-"),
+            ("[>1.0 <=2.0]", "vers:conan/>1.0|<=2.0"),
+            // `~` bumps the second item, or the first if there is only one
+            ("[~1.2.3]", "vers:conan/>=1.2.3-|<1.3-"),
+            ("[~1]", "vers:conan/>=1-|<2-"),
+            // `^` bumps the first non-zero item
+            ("[^1.2]", "vers:conan/>=1.2-|<2-"),
+            ("[^0.1.2]", "vers:conan/>=0.1.2-|<0.2-"),
+            ("[^0.0.3]", "vers:conan/>=0.0.3-|<0.0.4-"),
+            ("[>1 || <0.5]", "vers:conan/<0.5-|>1"),
+            ("[>1 >2]", "vers:conan/>2"),
+            ("[*]", "vers:conan/*"),
+            ("[]", "vers:conan/*"),
+            ("[1.2.3]", "vers:conan/1.2.3"),
+            ("[=1.2.3]", "vers:conan/1.2.3"),
+        ] {
+            let range = DynamicVersionRange::parse_native("conan", raw).unwrap();
+            assert_eq!(range.to_string(), expected, "{raw}");
+        }
+    }
+
+    #[test]
+    fn test_conan_include_prerelease() {
+        let range = DynamicVersionRange::parse_native("conan", "[>=1.0 <2.0]").unwrap();
+        assert!(range.contains("1.0".to_string()).unwrap());
+        assert!(range.contains("1.9.9.9+build".to_string()).unwrap());
+        assert!(!range.contains("1.5-beta".to_string()).unwrap());
+        assert!(!range.contains("2.0-beta".to_string()).unwrap());
+        assert!(!range.contains("2.0".to_string()).unwrap());
+
+        for raw in [
+            "[>=1.0 <2.0, include_prerelease]",
+            "[>=1.0 <2.0, include_prerelease=True]",
+        ] {
+            let range = ConanVersionRange::from_native_string("conan", raw).unwrap();
+            assert!(range.include_prerelease);
+            assert!(range.contains(&"1.0-alpha".parse().unwrap()).unwrap());
+            // The pre-releases of an exclusive upper bound stay excluded
+            assert!(!range.contains(&"2.0-beta".parse().unwrap()).unwrap());
+        }
+
+        // An inclusive upper bound keeps its pre-releases once they are allowed
+        let range =
+            DynamicVersionRange::parse_native("conan", "[<=2.0, include_prerelease]").unwrap();
+        assert!(range.contains("2.0-beta".to_string()).unwrap());
+        assert!(!range.contains("2.0+build".to_string()).unwrap());
+    }
+
+    #[test]
+    fn test_conan_invalid_ranges() {
+        for raw in [
+            "[>=1.0",
+            "[>=]",
+            "[>>1.0]",
+            "[^0.0]",
+            "[~1.x]",
+            "[>1, foo]",
+            "[>2 <1]",
+        ] {
+            assert!(
+                DynamicVersionRange::parse_native("conan", raw).is_err(),
+                "{raw}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_conan_vers_ranges_exclude_pre_releases() {
+        // A vers range carries no Conan options, so it gets the default of a
+        // native range and pre-releases within it do not match
+        let range: DynamicVersionRange = "vers:conan/>=1.2-|<1.3-".parse().unwrap();
+        assert_eq!(range.versioning_scheme(), "conan");
+        assert_eq!(range.to_string(), "vers:conan/>=1.2-|<1.3-");
+        assert!(range.contains("1.2.7".to_string()).unwrap());
+        assert!(!range.contains("1.2.7-beta".to_string()).unwrap());
+        assert!(!range.contains("1.3-rc1".to_string()).unwrap());
+    }
+
+    #[test]
+    fn test_conan_range_round_trips() {
+        let filtered = DynamicVersionRange::parse_native("conan", "[>=1.0 <2.0]").unwrap();
+        let unfiltered =
+            DynamicVersionRange::parse_native("conan", "[>=1.0 <2.0, include_prerelease]").unwrap();
+
+        // The option survives serde
+        for range in [&filtered, &unfiltered] {
+            let json = serde_json::to_string(range).unwrap();
+            let back: DynamicVersionRange = serde_json::from_str(&json).unwrap();
+            assert_eq!(&back, range, "{json}");
+            assert_eq!(
+                back.contains("1.5-beta".to_string()).unwrap(),
+                range.contains("1.5-beta".to_string()).unwrap()
+            );
+        }
+        assert_eq!(
+            serde_json::to_string(&unfiltered).unwrap(),
+            r#"{"versioning_scheme":"conan","constraints":[{"comparator":"GreaterThanOrEqual","version":"1.0-"},{"comparator":"LessThan","version":"2.0-"}],"include_prerelease":true}"#
+        );
+
+        // Serialized ranges without the option get the native default
+        let json = r#"{"versioning_scheme":"conan","constraints":[{"comparator":"GreaterThanOrEqual","version":"1.0-"}]}"#;
+        let range: DynamicVersionRange = serde_json::from_str(json).unwrap();
+        assert!(!range.contains("1.5-beta".to_string()).unwrap());
+
+        // The vers string keeps the default only
+        let back: DynamicVersionRange = filtered.to_string().parse().unwrap();
+        assert_eq!(back, filtered);
+        let back: DynamicVersionRange = unfiltered.to_string().parse().unwrap();
+        assert_eq!(back, filtered);
+    }
+}
@@ -0,0 +1,554 @@
+use crate::comparator::Comparator;
+use crate::constraint::NativeVersionConverter;
+use crate::range::interval::{Interval, IntervalSet};
+use crate::{VersError, VersVersionRange, VersionConstraint};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+/// Scheme identifier string for conda versions
+pub const CONDA_SCHEME: &str = "conda";
+
+/// Macro to create InvalidVersionFormat errors for conda versions
+macro_rules! conda_format_error {
+    ($s:expr, $msg:expr) => {
+        VersError::InvalidVersionFormat(CONDA_SCHEME.to_string(), $s.to_string(), $msg.into())
+    };
+}
+
+/// A single element of a conda version component.
+#[derive(Clone, Debug)]
+enum Element {
+    Number(u64),
+    Text(String),
+    /// `post`, which sorts after everything else
+    Infinity,
+}
+
+impl Element {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Element::Infinity, Element::Infinity) => Ordering::Equal,
+            (Element::Infinity, _) => Ordering::Greater,
+            (_, Element::Infinity) => Ordering::Less,
+            (Element::Number(a), Element::Number(b)) => a.cmp(b),
+            (Element::Text(a), Element::Text(b)) => a.cmp(b),
+            (Element::Text(_), Element::Number(_)) => Ordering::Less,
+            (Element::Number(_), Element::Text(_)) => Ordering::Greater,
+        }
+    }
+}
+
+/// Conda package version, ordered like conda's `VersionOrder`.
+///
+/// A version has the form `[epoch!]version[+local]` and is compared
+/// case-insensitively:
+/// - The epoch is an integer and is compared first (default `0`)
+/// - The version and the local version are split into components at `.` and
+///   `_` (or `-` if there is no `_`), and each component into runs of digits
+///   and runs of letters
+/// - Numbers compare numerically and sort after strings, strings compare
+///   lexicographically, `dev` sorts before all other strings and `post`
+///   after everything, so `1.0dev < 1.0_ < 1.0a < 1.0b < 1.0rc < 1.0 < 1.0post`
+/// - Missing components count as zero, so `1.0 == 1.0.0`
+///
+/// The original string is preserved for display.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CondaVersion {
+    value: String,
+    epoch: u64,
+    version: Vec<Vec<Element>>,
+    local: Vec<Vec<Element>>,
+}
+
+impl CondaVersion {
+    /// The epoch of this version, `0` if none was given.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Check whether this version has a local version part (`+...`).
+    pub fn has_local(&self) -> bool {
+        !self.local.is_empty()
+    }
+}
+
+/// Split a version or local version string into its components.
+fn parse_components(s: &str, full: &str) -> Result<Vec<Vec<Element>>, VersError> {
+    let s = if s.contains('-') && !s.contains('_') {
+        s.replace('-', "_")
+    } else {
+        s.to_string()
+    };
+    if s.is_empty() {
+        return Err(conda_format_error!(full, "empty version component"));
+    }
+    if let Some(c) = s
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '_')))
+    {
+        return Err(conda_format_error!(
+            full,
+            format!("invalid character '{}'", c)
+        ));
+    }
+
+    // A trailing underscore is kept as part of the last component, so that
+    // `1.0.1_` sorts after `1.0.1dev` but before `1.0.1a` and `1.0.1`
+    let (s, trailing_underscore) = match s.strip_suffix('_') {
+        Some(rest) => (rest.to_string(), true),
+        None => (s, false),
+    };
+
+    let mut components =
+        s.replace('_', ".")
+            .split('.')
+            .map(|part| {
+                if part.is_empty() {
+                    return Err(conda_format_error!(full, "empty version component"));
+                }
+                let mut elements = Vec::new();
+                let mut rest = part;
+                while let Some(c) = rest.chars().next() {
+                    let is_digit = c.is_ascii_digit();
+                    let end = rest
+                        .find(|c: char| c.is_ascii_digit() != is_digit)
+                        .unwrap_or(rest.len());
+                    let (run, remainder) = rest.split_at(end);
+                    elements.push(if is_digit {
+                        Element::Number(run.parse().map_err(|e| {
+                            conda_format_error!(full, format!("invalid number: {e}"))
+                        })?)
+                    } else {
+                        match run {
+                            "dev" => Element::Text("DEV".to_string()),
+                            "post" => Element::Infinity,
+                            _ => Element::Text(run.to_string()),
+                        }
+                    });
+                    rest = remainder;
+                }
+                if !matches!(elements[0], Element::Number(_)) {
+                    elements.insert(0, Element::Number(0));
+                }
+                Ok(elements)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+    if trailing_underscore && let Some(last) = components.last_mut() {
+        last.push(Element::Text("_".to_string()));
+    }
+    Ok(components)
+}
+
+/// Compare two component lists, padding missing components and elements
+/// with zeros.
+fn cmp_components(a: &[Vec<Element>], b: &[Vec<Element>]) -> Ordering {
+    let zero = Element::Number(0);
+    for i in 0..a.len().max(b.len()) {
+        let lhs = a.get(i).map_or(&[][..], Vec::as_slice);
+        let rhs = b.get(i).map_or(&[][..], Vec::as_slice);
+        for j in 0..lhs.len().max(rhs.len()) {
+            let ord = lhs.get(j).unwrap_or(&zero).cmp(rhs.get(j).unwrap_or(&zero));
+            if ord != Ordering::Equal {
+                return ord;
+            }
+        }
+    }
+    Ordering::Equal
+}
+
+impl Default for CondaVersion {
+    fn default() -> Self {
+        CondaVersion {
+            value: "0".to_string(),
+            epoch: 0,
+            version: vec![vec![Element::Number(0)]],
+            local: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for CondaVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl FromStr for CondaVersion {
+    type Err = VersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.trim().to_lowercase();
+        if lower.is_empty() {
+            return Err(conda_format_error!(s, "empty"));
+        }
+
+        let (epoch, rest) = match lower.split_once('!') {
+            Some((epoch, rest)) => (
+                epoch
+                    .parse::<u64>()
+                    .map_err(|e| conda_format_error!(s, format!("invalid epoch: {e}")))?,
+                rest,
+            ),
+            None => (0, lower.as_str()),
+        };
+
+        let (version, local) = match rest.split_once('+') {
+            Some((version, local)) => (version, parse_components(local, s)?),
+            None => (rest, Vec::new()),
+        };
+
+        Ok(CondaVersion {
+            value: s.trim().to_string(),
+            epoch,
+            version: parse_components(version, s)?,
+            local,
+        })
+    }
+}
+
+impl Ord for CondaVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.epoch
+            .cmp(&other.epoch)
+            .then_with(|| cmp_components(&self.version, &other.version))
+            .then_with(|| cmp_components(&self.local, &other.local))
+    }
+}
+
+impl PartialOrd for CondaVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Equality follows the ordering, so that `1.0` equals `1.0.0`
+impl PartialEq for CondaVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for CondaVersion {}
+
+impl TryFrom<String> for CondaVersion {
+    type Error = VersError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<CondaVersion> for String {
+    fn from(v: CondaVersion) -> Self {
+        v.value
+    }
+}
+
+impl NativeVersionConverter for CondaVersion {
+    const SCHEME_NAME: &'static str = CONDA_SCHEME;
+
+    /// Parse the version part of a conda match spec like `>=1.2,<2|1.0.*`.
+    ///
+    /// Supported syntax:
+    /// - The comparison operators `==`, `!=`, `>`, `>=`, `<` and `<=`; a
+    ///   version without an operator means `==`
+    /// - Prefix matches: `1.2.*` and `1.2*` match `1.2` and every version
+    ///   starting with it, as does the fuzzy `=1.2`; `!=1.2.*` negates a
+    ///   prefix match
+    /// - Compatible releases: `~=1.2.3` means `>=1.2.3,1.2.*`
+    /// - `*`, matching every version
+    /// - `,` (and) and `|` (or), where `,` binds tighter, and parentheses
+    ///
+    /// A prefix match `1.2.*` is expressed as `>=1.2dev|<1.3dev`, since
+    /// `dev` versions are the lowest versions starting with a prefix.
+    fn from_native_string(scheme: &str, raw: &str) -> Result<VersVersionRange<Self>, VersError> {
+        let mut parser = MatchSpecParser { raw, rest: raw };
+        let set = parser.parse_disjunction()?;
+        parser.skip_whitespace();
+        if !parser.rest.is_empty() {
+            return Err(parser.error("unexpected input"));
+        }
+
+        let mut range = VersVersionRange::new(scheme.to_string(), set.to_constraints()?);
+        range.normalize_and_validate()?;
+        Ok(range)
+    }
+}
+
+/// Recursive descent parser for conda match spec version constraints.
+struct MatchSpecParser<'a> {
+    raw: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> MatchSpecParser<'a> {
+    fn error(&self, msg: &str) -> VersError {
+        VersError::InvalidConstraint(format!(
+            "{} at '{}' in conda match spec '{}'",
+            msg, self.rest, self.raw
+        ))
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    fn eat(&mut self, token: char) -> bool {
+        self.skip_whitespace();
+        match self.rest.strip_prefix(token) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn parse_disjunction(&mut self) -> Result<IntervalSet<CondaVersion>, VersError> {
+        let mut set = self.parse_conjunction()?;
+        while self.eat('|') {
+            set = set.union(self.parse_conjunction()?);
+        }
+        Ok(set)
+    }
+
+    fn parse_conjunction(&mut self) -> Result<IntervalSet<CondaVersion>, VersError> {
+        let mut set = self.parse_atom()?;
+        while self.eat(',') {
+            set = set.intersection(&self.parse_atom()?);
+        }
+        Ok(set)
+    }
+
+    fn parse_atom(&mut self) -> Result<IntervalSet<CondaVersion>, VersError> {
+        if self.eat('(') {
+            let set = self.parse_disjunction()?;
+            if !self.eat(')') {
+                return Err(self.error("expected ')'"));
+            }
+            return Ok(set);
+        }
+
+        self.skip_whitespace();
+        let end = self
+            .rest
+            .find([',', '|', '(', ')'])
+            .unwrap_or(self.rest.len());
+        let atom = self.rest[..end].trim();
+        if atom.is_empty() {
+            return Err(self.error("expected a version constraint"));
+        }
+        let set = parse_constraint(atom)?;
+        self.rest = &self.rest[end..];
+        Ok(set)
+    }
+}
+
+/// Expand a single constraint like `>=1.2` or `1.2.*` into a set of versions.
+fn parse_constraint(atom: &str) -> Result<IntervalSet<CondaVersion>, VersError> {
+    if atom == "*" {
+        return Ok(IntervalSet::all());
+    }
+
+    let (operator, version) = ["==", "!=", "~=", ">=", "<=", ">", "<", "="]
+        .into_iter()
+        .find_map(|op| atom.strip_prefix(op).map(|rest| (op, rest.trim())))
+        .unwrap_or(("", atom));
+    if version.is_empty() {
+        return Err(VersError::InvalidConstraint(format!(
+            "missing version in conda constraint '{}'",
+            atom
+        )));
+    }
+
+    let wildcard = version.strip_suffix('*').map(|v| v.trim_end_matches('.'));
+    match (operator, wildcard) {
+        ("" | "=" | "==", Some(prefix)) => return Ok(prefix_match(prefix)?.into()),
+        ("=", None) => return Ok(prefix_match(version)?.into()),
+        ("!=", Some(prefix)) => {
+            let matched = prefix_match(prefix)?;
+            let below = matched.lower.map(|b| Interval::below(b.version, false));
+            let above = matched.upper.map(|b| Interval::above(b.version, true));
+            return Ok(IntervalSet::from_intervals(
+                below.into_iter().chain(above).collect(),
+            ));
+        }
+        ("~=", None) => {
+            let (prefix, _) = version.rsplit_once('.').ok_or_else(|| {
+                VersError::InvalidConstraint(format!(
+                    "'~=' requires at least two components in conda constraint '{}'",
+                    atom
+                ))
+            })?;
+            let lower: CondaVersion = version.parse()?;
+            return Ok(IntervalSet::from(Interval::above(lower, true))
+                .intersection(&prefix_match(prefix)?.into()));
+        }
+        ("~=", Some(_)) => {
+            return Err(VersError::InvalidConstraint(format!(
+                "wildcards are not allowed with '~=' in conda constraint '{}'",
+                atom
+            )));
+        }
+        _ => {}
+    }
+
+    // Like conda, ordered comparisons ignore a trailing wildcard
+    let version: CondaVersion = wildcard.unwrap_or(version).parse()?;
+    let comparator = match operator {
+        "!=" => Comparator::NotEqual,
+        ">=" => Comparator::GreaterThanOrEqual,
+        "<=" => Comparator::LessThanOrEqual,
+        ">" => Comparator::GreaterThan,
+        "<" => Comparator::LessThan,
+        _ => Comparator::Equal,
+    };
+    Ok(IntervalSet::from_constraint(&VersionConstraint::new(
+        comparator, version,
+    )))
+}
+
+/// The versions starting with `prefix`: from `{prefix}dev` up to the next
+/// prefix's `dev` version, e.g. `>=1.2dev` and `<1.3dev` for `1.2`.
+fn prefix_match(prefix: &str) -> Result<Interval<CondaVersion>, VersError> {
+    let (head, last) = match prefix.rsplit_once('.') {
+        Some((head, last)) => (format!("{}.", head), last),
+        None => (String::new(), prefix),
+    };
+    let next = last.parse::<u64>().map_err(|_| {
+        VersError::InvalidConstraint(format!(
+            "prefix match '{}.*' must end with a numeric component",
+            prefix
+        ))
+    })? + 1;
+
+    Ok(Interval::half_open(
+        format!("{}dev", prefix).parse()?,
+        format!("{}{}dev", head, next).parse()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CondaVersion;
+    use crate::range::VersionRange;
+    use crate::range::dynamic::DynamicVersionRange;
+
+    #[test]
+    fn test_conda_version_ordering() {
+        // The ordering example from the conda documentation
+        let versions: Vec<CondaVersion> = [
+            "0.4",
+            "0.4.0",
+            "0.4.1.rc",
+            "0.4.1.RC",
+            "0.4.1",
+            "0.5a1",
+            "0.5b3",
+            "0.5C1",
+            "0.5",
+            "0.9.6",
+            "0.960923",
+            "1.0",
+            "1.1dev1",
+            "1.1_",
+            "1.1a1",
+            "1.1.0dev1",
+            "1.1.a1",
+            "1.1.0rc1",
+            "1.1.0",
+            "1.1.0post1",
+            "1.1post1",
+            "1996.07.12",
+            "1!0.4.1",
+            "1!3.1.1.6",
+            "2!0.4.1",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+        for pair in versions.windows(2) {
+            assert!(pair[0] <= pair[1], "{} <= {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_conda_equivalent_spellings() {
+        // Letters compare case-insensitively, missing components count as
+        // zero and `-` separates components when there is no `_`
+        for (a, b) in [
+            ("0.4", "0.4.0"),
+            ("0.4.1.rc", "0.4.1.RC"),
+            ("1.0RC1", "1.0rc1"),
+            ("1.0-2", "1.0_2"),
+            ("0!1.0", "1.0"),
+        ] {
+            let (va, vb): (CondaVersion, CondaVersion) = (a.parse().unwrap(), b.parse().unwrap());
+            assert_eq!(va, vb, "{a} == {b}");
+            assert_eq!(va.to_string(), a);
+        }
+
+        let version: CondaVersion = "2!1.0+abc.2".parse().unwrap();
+        assert_eq!(version.epoch(), 2);
+        assert!(version.has_local());
+        assert!(version < "2!1.0+abc.10".parse().unwrap());
+
+        // Mixing `-` and `_` is ambiguous and therefore rejected
+        for s in ["", "a!1.0", "1..0", "1.0+", "1.0-1_2", "1.0$"] {
+            assert!(s.parse::<CondaVersion>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_conda_match_specs() {
+        for (raw, expected) in [
+            (">=1.2,<2", "vers:conda/>=1.2|<2"),
+            ("1.2|1.3", "vers:conda/1.2|1.3"),
+            ("==1.2", "vers:conda/1.2"),
+            ("!=1.2", "vers:conda/!=1.2"),
+            ("*", "vers:conda/*"),
+            // Ordered comparisons ignore a trailing wildcard
+            (">=1.0.*", "vers:conda/>=1.0"),
+            // `,` binds tighter than `|`
+            ("1.2|>=1.3,<1.2.5", "vers:conda/1.2"),
+            ("(1.2.*|>=2),!=2.1", "vers:conda/>=1.2dev|<1.3dev|>=2|!=2.1"),
+        ] {
+            let range = DynamicVersionRange::parse_native("conda", raw).unwrap();
+            assert_eq!(range.to_string(), expected, "{raw}");
+        }
+
+        for raw in ["", ">=", "1.2,", "(1.2", "~=1", "1.2a.*", ">2,<1"] {
+            assert!(
+                DynamicVersionRange::parse_native("conda", raw).is_err(),
+                "{raw}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_conda_prefix_matches() {
+        // `dev` versions are the lowest versions starting with a prefix
+        for raw in ["1.2.*", "1.2*", "=1.2"] {
+            let range = DynamicVersionRange::parse_native("conda", raw).unwrap();
+            assert_eq!(range.to_string(), "vers:conda/>=1.2dev|<1.3dev", "{raw}");
+            for version in ["1.2", "1.2.0", "1.2.5", "1.2a1", "1.2dev0", "1.2post1"] {
+                assert!(range.contains(version.to_string()).unwrap(), "{version}");
+            }
+            for version in ["1.1.9", "1.3", "1.3dev", "1.20", "1.3rc1"] {
+                assert!(!range.contains(version.to_string()).unwrap(), "{version}");
+            }
+        }
+
+        for (raw, expected) in [
+            ("!=1.2.*", "vers:conda/<1.2dev|>=1.3dev"),
+            ("~=1.2.3", "vers:conda/>=1.2.3|<1.3dev"),
+        ] {
+            let range = DynamicVersionRange::parse_native("conda", raw).unwrap();
+            assert_eq!(range.to_string(), expected, "{raw}");
+        }
+    }
+}
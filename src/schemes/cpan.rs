@@ -0,0 +1,248 @@
+use crate::VersError;
+use crate::constraint::NativeVersionConverter;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+/// Scheme identifier string for CPAN versions
+pub const CPAN_SCHEME: &str = "cpan";
+
+/// Macro to create InvalidVersionFormat errors for CPAN versions
+macro_rules! cpan_format_error {
+    ($s:expr, $msg:expr) => {
+        VersError::InvalidVersionFormat(CPAN_SCHEME.to_string(), $s.to_string(), $msg.into())
+    };
+}
+
+/// Perl distribution version, compared like `version.pm` does.
+///
+/// Perl knows two version formats, which are converted to a common list of
+/// integer components before comparing:
+/// - Decimal versions like `1.002003`, whose fraction is split into groups
+///   of three digits, padded with zeros on the right: `1.002003` is `1.2.3`
+///   and `1.5` is `1.500`
+/// - Dotted-decimal versions like `v1.2.3` or `1.2.3`, marked by a leading
+///   `v` or by at least two dots, whose components are taken as they are
+///
+/// An underscore marks a developer release (`1.002_003`). It is ignored for
+/// ordering, so `1.002_003 == 1.002003`. Missing components count as zero.
+///
+/// The original string is preserved for display.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CpanVersion {
+    value: String,
+    components: Vec<u64>,
+    dev_release: bool,
+}
+
+impl CpanVersion {
+    /// Check whether this is a developer release, marked by an underscore.
+    pub fn is_dev_release(&self) -> bool {
+        self.dev_release
+    }
+
+    /// Check whether this version was written in dotted-decimal form.
+    pub fn is_dotted(&self) -> bool {
+        self.value.starts_with('v') || self.value.matches('.').count() > 1
+    }
+
+    /// The normalized dotted-decimal form with at least three components,
+    /// as returned by `version->normal` (`1.002003` → `v1.2.3`).
+    pub fn normal(&self) -> String {
+        let mut components = self.components.clone();
+        components.resize(components.len().max(3), 0);
+        let parts: Vec<String> = components.iter().map(u64::to_string).collect();
+        format!("v{}", parts.join("."))
+    }
+}
+
+impl Default for CpanVersion {
+    fn default() -> Self {
+        CpanVersion {
+            value: "0".to_string(),
+            components: vec![0],
+            dev_release: false,
+        }
+    }
+}
+
+impl fmt::Display for CpanVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl FromStr for CpanVersion {
+    type Err = VersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(cpan_format_error!(s, "empty"));
+        }
+
+        let dev_release = s.contains('_');
+        let stripped = s.replace('_', "");
+        let (dotted, digits) = match stripped.strip_prefix('v') {
+            Some(rest) => (true, rest),
+            None => (stripped.matches('.').count() > 1, stripped.as_str()),
+        };
+
+        if !digits.starts_with(|c: char| c.is_ascii_digit())
+            || !digits.chars().all(|c| c.is_ascii_digit() || c == '.')
+            || digits.contains("..")
+        {
+            return Err(cpan_format_error!(s, "invalid version number"));
+        }
+        if dev_release && (s.starts_with('_') || s.ends_with('_') || s.contains("._")) {
+            return Err(cpan_format_error!(s, "misplaced underscore"));
+        }
+
+        let parse = |part: &str| {
+            part.parse::<u64>()
+                .map_err(|e| cpan_format_error!(s, format!("invalid component: {e}")))
+        };
+
+        let components = if dotted {
+            if digits.ends_with('.') {
+                return Err(cpan_format_error!(s, "trailing dot"));
+            }
+            digits.split('.').map(parse).collect::<Result<_, _>>()?
+        } else {
+            let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+            let mut components = vec![parse(integer)?];
+            // the fraction is ASCII digits only, so byte chunks are valid UTF-8
+            for chunk in fraction.as_bytes().chunks(3) {
+                let group = String::from_utf8_lossy(chunk);
+                components.push(parse(&format!("{group:0<3}"))?);
+            }
+            components
+        };
+
+        Ok(CpanVersion {
+            value: s.to_string(),
+            components,
+            dev_release,
+        })
+    }
+}
+
+impl Ord for CpanVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let len = self.components.len().max(other.components.len());
+        for i in 0..len {
+            let a = self.components.get(i).copied().unwrap_or(0);
+            let b = other.components.get(i).copied().unwrap_or(0);
+            match a.cmp(&b) {
+                Ordering::Equal => continue,
+                ord => return ord,
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+impl PartialOrd for CpanVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Equality follows the ordering, so that `1.002003` equals `v1.2.3`
+impl PartialEq for CpanVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for CpanVersion {}
+
+impl TryFrom<String> for CpanVersion {
+    type Error = VersError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<CpanVersion> for String {
+    fn from(v: CpanVersion) -> Self {
+        v.value
+    }
+}
+
+impl NativeVersionConverter for CpanVersion {
+    const SCHEME_NAME: &'static str = CPAN_SCHEME;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CpanVersion;
+    use crate::range::VersionRange;
+    use crate::range::dynamic::DynamicVersionRange;
+
+    #[test]
+    fn test_cpan_decimal_and_dotted_equivalence() {
+        // The decimal fraction is read in groups of three digits, padded on
+        // the right; underscores and missing components do not count
+        for (a, b, normal) in [
+            ("1.002003", "v1.2.3", "v1.2.3"),
+            ("1.002003", "1.2.3", "v1.2.3"),
+            ("1.5", "v1.500", "v1.500.0"),
+            ("1.2", "1.200", "v1.200.0"),
+            ("v1.2", "v1.2.0", "v1.2.0"),
+            ("5", "5.000", "v5.0.0"),
+            ("1.0000001", "v1.0.0.100", "v1.0.0.100"),
+            ("1.002_003", "1.002003", "v1.2.3"),
+        ] {
+            let (va, vb): (CpanVersion, CpanVersion) = (a.parse().unwrap(), b.parse().unwrap());
+            assert_eq!(va, vb, "{a} == {b}");
+            assert_eq!(va.normal(), normal, "{a}");
+            assert_eq!(va.to_string(), a);
+        }
+    }
+
+    #[test]
+    fn test_cpan_ordering() {
+        // `1.10` is the decimal 1.100, while `v1.10` has the component 10
+        let versions: Vec<CpanVersion> = [
+            "0.99", "1.0", "1.0001", "1.001", "v1.5", "v1.10", "1.10", "1.5", "1.500001",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+        for pair in versions.windows(2) {
+            assert!(pair[0] < pair[1], "{} < {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_cpan_version_forms() {
+        let version: CpanVersion = "1.002_003".parse().unwrap();
+        assert!(version.is_dev_release());
+        assert!(!version.is_dotted());
+        let version: CpanVersion = "1.2.3".parse().unwrap();
+        assert!(!version.is_dev_release());
+        assert!(version.is_dotted());
+        let version: CpanVersion = "v2".parse().unwrap();
+        assert!(version.is_dotted());
+
+        for s in [
+            "", "v", "abc", "1..2", "1.2.", "_1.2", "1.2_", "1._2", "1.2-3", "1.x", "v1.2a",
+        ] {
+            assert!(s.parse::<CpanVersion>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_cpan_mixed_forms_in_range() {
+        let range: DynamicVersionRange = "vers:cpan/>=1.002|<2".parse().unwrap();
+        assert_eq!(range.versioning_scheme(), "cpan");
+        assert!(range.contains("v1.2.0".to_string()).unwrap());
+        assert!(range.contains("1.010_01".to_string()).unwrap());
+        assert!(range.contains("v1.999.999".to_string()).unwrap());
+        assert!(!range.contains("1.001".to_string()).unwrap());
+        assert!(!range.contains("v2.0.0".to_string()).unwrap());
+    }
+}
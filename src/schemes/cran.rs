@@ -0,0 +1,264 @@
+use crate::comparator::Comparator;
+use crate::constraint::NativeVersionConverter;
+use crate::range::interval::IntervalSet;
+use crate::{VersError, VersVersionRange, VersionConstraint};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+/// Scheme identifier string for CRAN versions
+pub const CRAN_SCHEME: &str = "cran";
+
+/// Macro to create InvalidVersionFormat errors for CRAN versions
+macro_rules! cran_format_error {
+    ($s:expr, $msg:expr) => {
+        VersError::InvalidVersionFormat(CRAN_SCHEME.to_string(), $s.to_string(), $msg.into())
+    };
+}
+
+/// R package version, ordered like R's `package_version`.
+///
+/// A version consists of at least two non-negative integers separated by `.`
+/// or `-`, which are equivalent: `1.2-3` equals `1.2.3`. Versions compare
+/// component by component, and a version that is a prefix of another sorts
+/// first, so `1.2 < 1.2.0`.
+///
+/// The original string is preserved for display.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CranVersion {
+    value: String,
+    components: Vec<u64>,
+}
+
+impl CranVersion {
+    /// The integer components of this version.
+    pub fn components(&self) -> &[u64] {
+        &self.components
+    }
+}
+
+impl Default for CranVersion {
+    fn default() -> Self {
+        CranVersion {
+            value: "0.0".to_string(),
+            components: vec![0, 0],
+        }
+    }
+}
+
+impl fmt::Display for CranVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl FromStr for CranVersion {
+    type Err = VersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(cran_format_error!(s, "empty"));
+        }
+
+        let components = s
+            .split(['.', '-'])
+            .map(|part| {
+                if part.is_empty() || !part.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(cran_format_error!(
+                        s,
+                        format!("invalid component '{}'", part)
+                    ));
+                }
+                part.parse::<u64>()
+                    .map_err(|e| cran_format_error!(s, format!("invalid component: {e}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if components.len() < 2 {
+            return Err(cran_format_error!(
+                s,
+                "at least two components are required"
+            ));
+        }
+
+        Ok(CranVersion {
+            value: s.to_string(),
+            components,
+        })
+    }
+}
+
+impl Ord for CranVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.components.cmp(&other.components)
+    }
+}
+
+impl PartialOrd for CranVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Equality follows the ordering, so that `1.2-3` equals `1.2.3`
+impl PartialEq for CranVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for CranVersion {}
+
+impl TryFrom<String> for CranVersion {
+    type Error = VersError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<CranVersion> for String {
+    fn from(v: CranVersion) -> Self {
+        v.value
+    }
+}
+
+impl NativeVersionConverter for CranVersion {
+    const SCHEME_NAME: &'static str = CRAN_SCHEME;
+
+    /// Parse a dependency constraint as written in an R `DESCRIPTION` file.
+    ///
+    /// Each requirement is an operator and a version, optionally wrapped in
+    /// parentheses as in `(>= 1.2.0)`. Requirements separated by commas must
+    /// all hold, e.g. `(>= 1.2.0), (< 2.0)`. Supported operators are `>=`,
+    /// `>`, `<=`, `<`, `==` and `!=`.
+    fn from_native_string(scheme: &str, raw: &str) -> Result<VersVersionRange<Self>, VersError> {
+        let mut set = IntervalSet::all();
+        for requirement in raw.split(',') {
+            set = set.intersection(&IntervalSet::from_constraint(&parse_requirement(
+                requirement,
+            )?));
+        }
+
+        let mut range = VersVersionRange::new(scheme.to_string(), set.to_constraints()?);
+        range.normalize_and_validate()?;
+        Ok(range)
+    }
+}
+
+/// Parse a single requirement like `(>= 1.2.0)` into a vers constraint.
+fn parse_requirement(requirement: &str) -> Result<VersionConstraint<CranVersion>, VersError> {
+    let trimmed = requirement.trim();
+    let inner = match trimmed.strip_prefix('(') {
+        Some(rest) => rest.strip_suffix(')').ok_or_else(|| {
+            VersError::InvalidConstraint(format!(
+                "unbalanced parentheses in R requirement '{}'",
+                trimmed
+            ))
+        })?,
+        None => trimmed,
+    }
+    .trim();
+
+    let (comparator, version) = [
+        (">=", Comparator::GreaterThanOrEqual),
+        ("<=", Comparator::LessThanOrEqual),
+        ("==", Comparator::Equal),
+        ("!=", Comparator::NotEqual),
+        (">", Comparator::GreaterThan),
+        ("<", Comparator::LessThan),
+    ]
+    .into_iter()
+    .find_map(|(op, comparator)| {
+        inner
+            .strip_prefix(op)
+            .map(|version| (comparator, version.trim()))
+    })
+    .ok_or_else(|| {
+        VersError::InvalidConstraint(format!(
+            "missing or unknown operator in R requirement '{}'",
+            trimmed
+        ))
+    })?;
+
+    Ok(VersionConstraint::new(comparator, version.parse()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CranVersion;
+    use crate::range::VersionRange;
+    use crate::range::dynamic::DynamicVersionRange;
+
+    #[test]
+    fn test_cran_version_ordering() {
+        // Components compare as integers and a prefix sorts first
+        let versions: Vec<CranVersion> = [
+            "0.99.902", "1.0", "1.2", "1.2.0", "1.2.3", "1.2.3.1", "1.9-1", "1.10-0",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+        for pair in versions.windows(2) {
+            assert!(pair[0] < pair[1], "{} < {}", pair[0], pair[1]);
+        }
+
+        // `.` and `-` separate components alike, and leading zeros vanish
+        for (a, b) in [("1.2-3", "1.2.3"), ("1.02", "1.2"), ("2-1", "2.1")] {
+            let (va, vb): (CranVersion, CranVersion) = (a.parse().unwrap(), b.parse().unwrap());
+            assert_eq!(va, vb, "{a} == {b}");
+            assert_eq!(va.to_string(), a);
+        }
+
+        for s in [
+            "", "1", "1.", "-1.2", "1..2", "1.2a", "v1.2", "1.2_3", "1.2 3",
+        ] {
+            assert!(s.parse::<CranVersion>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_cran_description_constraints() {
+        for (raw, expected) in [
+            ("(>= 1.2.0)", "vers:cran/>=1.2.0"),
+            (">= 3.5", "vers:cran/>=3.5"),
+            ("( == 1.0-2 )", "vers:cran/1.0-2"),
+            ("(>= 1.2.0), (< 2.0)", "vers:cran/>=1.2.0|<2.0"),
+            ("(>=1.2.0),(!= 1.4-1)", "vers:cran/>=1.2.0|!=1.4-1"),
+        ] {
+            let range = DynamicVersionRange::parse_native("cran", raw).unwrap();
+            assert_eq!(range.to_string(), expected, "{raw}");
+        }
+
+        // Because `1.2 < 1.2.0`, an exact requirement does not match a
+        // longer spelling of the same release
+        let range = DynamicVersionRange::parse_native("cran", "(== 1.2)").unwrap();
+        assert!(range.contains("1.2".to_string()).unwrap());
+        assert!(range.contains("1-2".to_string()).unwrap());
+        assert!(!range.contains("1.2.0".to_string()).unwrap());
+
+        let range = DynamicVersionRange::parse_native("cran", "(< 1.2)").unwrap();
+        assert!(!range.contains("1.2.0".to_string()).unwrap());
+    }
+
+    #[test]
+    fn test_cran_invalid_constraints() {
+        // A bare version, as accepted by some other schemes, needs an
+        // operator in R
+        for raw in [
+            "",
+            "(>= 1.2.0",
+            "1.2.0",
+            "(~= 1.2)",
+            "(> 2.0), (< 1.0)",
+            "(>= 1.2.0),",
+        ] {
+            assert!(
+                DynamicVersionRange::parse_native("cran", raw).is_err(),
+                "{raw}"
+            );
+        }
+    }
+}
@@ -0,0 +1,279 @@
+//! Versioning for the Dart and Flutter package manager pub ("pub" scheme).
+//!
+//! The module is named after the language, since `pub` is a Rust keyword.
+
+use crate::constraint::NativeVersionConverter;
+use crate::range::interval::{Bound, Interval, IntervalSet};
+use crate::{VersError, VersVersionRange};
+use derive_more::Display;
+use semver::{BuildMetadata, Prerelease, Version};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+/// Scheme identifier string for pub versions
+pub const PUB_SCHEME: &str = "pub";
+
+/// A version of a pub package.
+///
+/// pub versions follow SemVer 2.0.0 syntax, with one difference in ordering:
+/// build metadata is significant. A version with build metadata sorts after
+/// the same version without it, and build identifiers are compared like
+/// pre-release identifiers (`1.0.0 < 1.0.0+1 < 1.0.0+2`).
+#[derive(Display, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PubVersion(Version);
+
+impl PubVersion {
+    /// Check whether this version carries a pre-release identifier.
+    pub fn is_prerelease(&self) -> bool {
+        !self.0.pre.is_empty()
+    }
+
+    /// The version pub considers the next breaking release: the next minor
+    /// version for `0.x` versions, the next major version otherwise.
+    fn next_breaking(&self) -> Self {
+        if self.0.major == 0 {
+            Self::release(0, self.0.minor + 1, 0)
+        } else {
+            Self::release(self.0.major + 1, 0, 0)
+        }
+    }
+
+    fn release(major: u64, minor: u64, patch: u64) -> Self {
+        PubVersion(Version::new(major, minor, patch))
+    }
+
+    /// The lowest pre-release of this version's release, e.g. `2.0.0-0`.
+    fn first_prerelease(&self) -> Self {
+        PubVersion(Version {
+            pre: Prerelease::new("0").expect("valid pre-release"),
+            build: BuildMetadata::EMPTY,
+            ..Version::new(self.0.major, self.0.minor, self.0.patch)
+        })
+    }
+
+    fn same_release(&self, other: &Self) -> bool {
+        (self.0.major, self.0.minor, self.0.patch) == (other.0.major, other.0.minor, other.0.patch)
+    }
+}
+
+impl Default for PubVersion {
+    fn default() -> Self {
+        Self::release(0, 0, 0)
+    }
+}
+
+impl PartialOrd for PubVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PubVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl FromStr for PubVersion {
+    type Err = VersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(PubVersion(Version::parse(s).map_err(|e| {
+            VersError::InvalidVersionFormat(PUB_SCHEME.to_string(), s.to_string(), e.to_string())
+        })?))
+    }
+}
+
+impl NativeVersionConverter for PubVersion {
+    const SCHEME_NAME: &'static str = PUB_SCHEME;
+
+    /// Parse a pub version constraint as written in `pubspec.yaml`.
+    ///
+    /// Supported syntax:
+    /// - `any`, matching every version
+    /// - A bare version like `1.2.3`, matching exactly that version
+    /// - Caret syntax `^1.2.3`, meaning `>=1.2.3 <2.0.0`; for `0.x` versions
+    ///   only the minor version is fixed, so `^0.1.2` means `>=0.1.2 <0.2.0`
+    /// - Comparisons with `>`, `>=`, `<` and `<=`, combined by whitespace into
+    ///   a conjunction like `>=1.0.0 <2.0.0`
+    ///
+    /// Like pub, an exclusive upper bound excludes the pre-releases of that
+    /// version (`<2.0.0` becomes `<2.0.0-0`), unless the lower bound is
+    /// itself a pre-release of the same version.
+    fn from_native_string(scheme: &str, raw: &str) -> Result<VersVersionRange<Self>, VersError> {
+        let mut interval = Interval::all();
+        let mut rest = raw.trim();
+
+        if rest.is_empty() {
+            return Err(VersError::EmptyConstraints);
+        }
+
+        while !rest.is_empty() {
+            let (clause, remainder) = parse_clause(rest)?;
+            interval = interval.intersect(&clause).ok_or_else(|| {
+                VersError::InvalidRange(format!(
+                    "pub constraint '{}' does not match any version",
+                    raw
+                ))
+            })?;
+            rest = remainder.trim_start();
+        }
+
+        let mut range = VersVersionRange::new(
+            scheme.to_string(),
+            IntervalSet::from(exclude_max_prereleases(interval)).to_constraints()?,
+        );
+        range.normalize_and_validate()?;
+        Ok(range)
+    }
+}
+
+/// Parse one clause from the start of `raw`, returning it together with the
+/// unparsed remainder.
+fn parse_clause(raw: &str) -> Result<(Interval<PubVersion>, &str), VersError> {
+    if let Some(rest) = raw.strip_prefix("any") {
+        return Ok((Interval::all(), rest));
+    }
+
+    let (operator, rest) = ["^", ">=", "<=", ">", "<"]
+        .iter()
+        .find_map(|op| raw.strip_prefix(op).map(|rest| (*op, rest.trim_start())))
+        .unwrap_or(("", raw));
+
+    let end = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+')))
+        .unwrap_or(rest.len());
+    let (version, rest) = rest.split_at(end);
+    if version.is_empty() {
+        return Err(VersError::InvalidConstraint(format!(
+            "missing version in pub constraint '{}'",
+            raw
+        )));
+    }
+    let version: PubVersion = version.parse()?;
+
+    let interval = match operator {
+        "^" => Interval::half_open(version.clone(), version.next_breaking().first_prerelease()),
+        ">=" => Interval::above(version, true),
+        ">" => Interval::above(version, false),
+        "<=" => Interval::below(version, true),
+        "<" => Interval::below(version, false),
+        _ => Interval::exactly(version),
+    };
+    Ok((interval, rest))
+}
+
+/// Apply pub's rule that `<2.0.0` does not allow pre-releases of `2.0.0`.
+fn exclude_max_prereleases(interval: Interval<PubVersion>) -> Interval<PubVersion> {
+    match interval.upper {
+        Some(Bound {
+            version: ref max,
+            inclusive: false,
+        }) if !max.is_prerelease()
+            && max.0.build.is_empty()
+            && !interval.lower.as_ref().is_some_and(|min| {
+                min.version.is_prerelease() && min.version.same_release(max)
+            }) =>
+        {
+            let upper = Bound {
+                version: max.first_prerelease(),
+                inclusive: false,
+            };
+            Interval::new(interval.lower, Some(upper))
+        }
+        _ => interval,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PubVersion;
+    use crate::VersError;
+    use crate::range::VersionRange;
+    use crate::range::dynamic::DynamicVersionRange;
+
+    #[test]
+    fn test_pub_build_metadata_ordering() {
+        // Unlike SemVer, build identifiers order versions, numerically where
+        // they are numbers
+        let versions: Vec<PubVersion> = [
+            "1.0.0-dev.2",
+            "1.0.0-dev.10",
+            "1.0.0",
+            "1.0.0+1",
+            "1.0.0+2",
+            "1.0.0+10",
+            "1.0.0+build",
+            "1.0.1-0",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+        for pair in versions.windows(2) {
+            assert!(pair[0] < pair[1], "{:?} < {:?}", pair[0], pair[1]);
+        }
+
+        let range = DynamicVersionRange::parse_native("pub", "<=1.0.0").unwrap();
+        assert!(range.contains("1.0.0".to_string()).unwrap());
+        assert!(!range.contains("1.0.0+1".to_string()).unwrap());
+    }
+
+    #[test]
+    fn test_pub_caret() {
+        // `^0.0.x` fixes only the minor version, like any other `0.x` version
+        for (raw, expected) in [
+            ("^1.2.3", "vers:pub/>=1.2.3|<2.0.0-0"),
+            ("^0.1.2", "vers:pub/>=0.1.2|<0.2.0-0"),
+            ("^0.0.3", "vers:pub/>=0.0.3|<0.1.0-0"),
+            ("^1.0.0-beta", "vers:pub/>=1.0.0-beta|<2.0.0-0"),
+            ("^ 1.2.3", "vers:pub/>=1.2.3|<2.0.0-0"),
+        ] {
+            let range = DynamicVersionRange::parse_native("pub", raw).unwrap();
+            assert_eq!(range.to_string(), expected, "{raw}");
+        }
+    }
+
+    #[test]
+    fn test_pub_upper_bound_pre_releases() {
+        let range = DynamicVersionRange::parse_native("pub", ">=1.0.0 <2.0.0").unwrap();
+        assert_eq!(range.to_string(), "vers:pub/>=1.0.0|<2.0.0-0");
+        assert!(range.contains("1.9.9+hotfix".to_string()).unwrap());
+        assert!(!range.contains("2.0.0-dev.1".to_string()).unwrap());
+
+        // Pre-releases stay allowed when the lower bound is a pre-release of
+        // the same version, or the upper bound has a pre-release or build
+        for (raw, expected) in [
+            (">=2.0.0-dev.1 <2.0.0", "vers:pub/>=2.0.0-dev.1|<2.0.0"),
+            ("<2.0.0-beta", "vers:pub/<2.0.0-beta"),
+            ("<2.0.0+1", "vers:pub/<2.0.0+1"),
+            (">=1.0.0 <=2.0.0", "vers:pub/>=1.0.0|<=2.0.0"),
+            (">1.0.0 >1.5.0 any", "vers:pub/>1.5.0"),
+        ] {
+            let range = DynamicVersionRange::parse_native("pub", raw).unwrap();
+            assert_eq!(range.to_string(), expected, "{raw}");
+        }
+    }
+
+    #[test]
+    fn test_pub_invalid_constraints() {
+        assert!(matches!(
+            DynamicVersionRange::parse_native("pub", ">=2.0.0 <1.0.0"),
+            Err(VersError::InvalidRange(_))
+        ));
+        for raw in [
+            "",
+            ">=",
+            "^",
+            "~1.0.0",
+            ">=1.0",
+            "anything",
+            "1.0.0 || 2.0.0",
+        ] {
+            assert!(
+                DynamicVersionRange::parse_native("pub", raw).is_err(),
+                "{raw}"
+            );
+        }
+    }
+}
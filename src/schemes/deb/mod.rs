@@ -0,0 +1,1011 @@
+use crate::VersError;
+use crate::VersVersionRange;
+use crate::VersionConstraint;
+use crate::comparator::Comparator;
+use crate::constraint::NativeVersionConverter;
+use crate::range::interval::{Bound, Interval, IntervalSet};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+pub mod relation;
+pub mod status;
+#[cfg(feature = "json")]
+pub mod tracker;
+
+/// Scheme identifier string for Debian versions
+pub const DEB_SCHEME: &str = "deb";
+
+/// Macro to create InvalidVersionFormat errors for Debian versions
+macro_rules! deb_format_error {
+    ($s:expr, $msg:expr) => {
+        VersError::InvalidVersionFormat(DEB_SCHEME.to_string(), $s.to_string(), $msg.into())
+    };
+}
+
+/// Debian version according to dpkg version format: `[epoch:]upstream[-debian]`
+///
+/// This implementation follows Debian Policy version comparison rules:
+/// - Epoch numeric (default 0)
+/// - Upstream version vs. Debian revision separated by last '-'
+/// - Tilde '~' sorts before the end and before any other character
+/// - Sequences of digits are compared numerically; non-digits character by
+///   character, with letters sorting before all other characters
+///
+/// The comparison key is computed once at construction, so that comparing
+/// two versions is a comparison of two token slices.
+#[derive(Clone, Debug, Eq, Serialize, Deserialize)]
+#[serde(from = "DebVersionParts")]
+pub struct DebVersion {
+    epoch: u64,
+    upstream: String,
+    debian_revision: String,
+    #[serde(skip)]
+    key: Box<[Token]>,
+}
+
+/// The serialized fields of a [`DebVersion`], from which the comparison key
+/// is rebuilt on deserialization.
+#[derive(Deserialize)]
+struct DebVersionParts {
+    epoch: u64,
+    upstream: String,
+    debian_revision: String,
+}
+
+impl From<DebVersionParts> for DebVersion {
+    fn from(parts: DebVersionParts) -> Self {
+        DebVersion::from_parts(parts.epoch, parts.upstream, parts.debian_revision)
+    }
+}
+
+/// A token of the comparison key of a [`DebVersion`].
+///
+/// The derived ordering is the dpkg ordering: non-digit characters are
+/// weighted so that `~` sorts before the end of a non-digit run, which sorts
+/// before letters, which sort before all other characters. Digit runs are
+/// compared numerically, with runs too large for a `u64` ordered by their
+/// length and then their digits.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Token {
+    NonDigit(u32),
+    Number(u64),
+    BigNumber(usize, Box<str>),
+}
+
+/// The weight of the end of a non-digit run.
+const END: Token = Token::NonDigit(1);
+
+impl DebVersion {
+    fn from_parts(epoch: u64, upstream: String, debian_revision: String) -> Self {
+        let mut key = vec![Token::Number(epoch)];
+        tokenize_part(&upstream, &mut key);
+        // An absent Debian revision compares like "0"
+        let revision = if debian_revision.is_empty() {
+            "0"
+        } else {
+            &debian_revision
+        };
+        tokenize_part(revision, &mut key);
+        DebVersion {
+            epoch,
+            upstream,
+            debian_revision,
+            key: key.into_boxed_slice(),
+        }
+    }
+
+    /// Start building a version from its upstream part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::schemes::deb::DebVersion;
+    ///
+    /// let version = DebVersion::builder("1.2.3").epoch(1).debian_revision("4ubuntu1").build().unwrap();
+    /// assert_eq!(version.to_string(), "1:1.2.3-4ubuntu1");
+    /// ```
+    pub fn builder(upstream: &str) -> DebVersionBuilder {
+        DebVersionBuilder {
+            epoch: 0,
+            upstream: upstream.to_string(),
+            debian_revision: String::new(),
+        }
+    }
+
+    /// The epoch, `0` if the version has none.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// The upstream version.
+    pub fn upstream(&self) -> &str {
+        &self.upstream
+    }
+
+    /// The Debian revision, or `None` for a native package version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::schemes::deb::DebVersion;
+    ///
+    /// let version: DebVersion = "2:4.17.12+dfsg-0+deb12u1".parse().unwrap();
+    /// assert_eq!(version.epoch(), 2);
+    /// assert_eq!(version.upstream(), "4.17.12+dfsg");
+    /// assert_eq!(version.debian_revision(), Some("0+deb12u1"));
+    /// ```
+    pub fn debian_revision(&self) -> Option<&str> {
+        (!self.debian_revision.is_empty()).then_some(self.debian_revision.as_str())
+    }
+
+    /// Check whether this is the version of a native package, i.e. has no
+    /// Debian revision.
+    pub fn is_native(&self) -> bool {
+        self.debian_revision.is_empty()
+    }
+}
+
+/// Builder for [`DebVersion`], created by [`DebVersion::builder`].
+#[derive(Clone, Debug)]
+pub struct DebVersionBuilder {
+    epoch: u64,
+    upstream: String,
+    debian_revision: String,
+}
+
+impl DebVersionBuilder {
+    /// Set the epoch.
+    pub fn epoch(mut self, epoch: u64) -> Self {
+        self.epoch = epoch;
+        self
+    }
+
+    /// Set the Debian revision. An empty revision means none.
+    pub fn debian_revision(mut self, debian_revision: &str) -> Self {
+        self.debian_revision = debian_revision.to_string();
+        self
+    }
+
+    /// Build the version, validating its parts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VersError::InvalidVersionFormat`] if a part contains invalid
+    /// characters, or if the upstream version contains a `-` without a
+    /// Debian revision, which would be read as one.
+    pub fn build(self) -> Result<DebVersion, VersError> {
+        let version = DebVersion::from_parts(self.epoch, self.upstream, self.debian_revision);
+        let mut rendered = format!("{}:{}", version.epoch, version.upstream);
+        if !version.debian_revision.is_empty() {
+            rendered = format!("{}-{}", rendered, version.debian_revision);
+        }
+        let parsed: DebVersion = rendered.parse()?;
+        if parsed.upstream != version.upstream {
+            return Err(deb_format_error!(
+                rendered,
+                "upstream_version must not contain '-' without a debian_revision"
+            ));
+        }
+        Ok(version)
+    }
+}
+
+impl Default for DebVersion {
+    fn default() -> Self {
+        DebVersion::from_parts(0, "0".to_string(), String::new())
+    }
+}
+
+impl NativeVersionConverter for DebVersion {
+    const SCHEME_NAME: &'static str = "deb";
+
+    /// Parse a Debian native constraint string into standard vers constraints.
+    ///
+    /// Debian defines the following comparison operators:
+    /// - `<<` (strictly less than) → vers `<`
+    /// - `<=` (less than or equal) → vers `<=`
+    /// - `=` (exactly equal) → vers `=`
+    /// - `>=` (greater than or equal) → vers `>=`
+    /// - `>>` (strictly greater than) → vers `>`
+    ///
+    /// Single `<`, `>`, and `!=` are **not** valid Debian comparators and will
+    /// be rejected. Invalid combinations like `>>=` or `<<=` are also rejected
+    /// because the remaining `=` cannot start a Debian version string.
+    fn from_native_constraint(raw: &str) -> Result<VersionConstraint<Self>, VersError> {
+        let raw = raw.trim();
+
+        if raw.is_empty() {
+            return Err(VersError::InvalidConstraint("Empty constraint".to_string()));
+        }
+
+        // Parse and validate the Debian comparator prefix.
+        // Valid Debian comparators: <<, <=, =, >=, >>
+        // Single <, >, and != are NOT valid Debian comparators.
+        let (comparator, version_str) = if let Some(stripped) = raw.strip_prefix("<<") {
+            (Comparator::LessThan, stripped)
+        } else if let Some(stripped) = raw.strip_prefix("<=") {
+            (Comparator::LessThanOrEqual, stripped)
+        } else if let Some(stripped) = raw.strip_prefix(">>") {
+            (Comparator::GreaterThan, stripped)
+        } else if let Some(stripped) = raw.strip_prefix(">=") {
+            (Comparator::GreaterThanOrEqual, stripped)
+        } else if let Some(stripped) = raw.strip_prefix('=') {
+            (Comparator::Equal, stripped)
+        } else {
+            return Err(VersError::InvalidConstraint(format!(
+                "invalid Debian comparator in '{}': valid comparators are <<, <=, =, >=, >>",
+                raw
+            )));
+        };
+
+        let version_str = version_str.trim();
+        if version_str.is_empty() {
+            return Err(VersError::InvalidConstraint("Missing version".to_string()));
+        }
+
+        let parsed_version = version_str.parse::<Self>().map_err(|_| {
+            VersError::InvalidConstraint(format!("Failed to parse version: {}", version_str))
+        })?;
+
+        Ok(VersionConstraint::new(comparator, parsed_version))
+    }
+
+    /// Render a range with the Debian comparators `<<`, `<=`, `=`, `>=` and
+    /// `>>`, separated by `|`, e.g. `>=1.0|<<2.0`.
+    ///
+    /// The result only round-trips through [`Self::from_native_string`]: it
+    /// keeps the vers constraint list, where `|` does not mean "or" as it
+    /// does in a relationship field. Use
+    /// [`Relationship::from_range`](relation::Relationship::from_range) for
+    /// a `Depends` field. Ranges with `!=` or `*` constraints have no Debian
+    /// equivalent and are rejected.
+    fn to_native(range: &VersVersionRange<Self>) -> Result<String, VersError> {
+        range
+            .constraints
+            .iter()
+            .map(|constraint| {
+                let operator = debian_operator(constraint.comparator).ok_or_else(|| {
+                    VersError::InvalidConstraint(format!(
+                        "'{}' has no Debian equivalent",
+                        constraint.comparator
+                    ))
+                })?;
+                Ok(format!("{}{}", operator, constraint.version))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(|relations| relations.join("|"))
+    }
+}
+
+/// The Debian operator for a vers comparator, if there is one.
+fn debian_operator(comparator: Comparator) -> Option<&'static str> {
+    match comparator {
+        Comparator::LessThan => Some("<<"),
+        Comparator::LessThanOrEqual => Some("<="),
+        Comparator::Equal => Some("="),
+        Comparator::GreaterThanOrEqual => Some(">="),
+        Comparator::GreaterThan => Some(">>"),
+        Comparator::NotEqual | Comparator::Any => None,
+    }
+}
+
+impl std::fmt::Display for DebVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.epoch > 0 {
+            write!(f, "{}:", self.epoch)?;
+        }
+        write!(f, "{}", self.upstream)?;
+        if !self.debian_revision.is_empty() {
+            write!(f, "-{}", self.debian_revision)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for DebVersion {
+    type Err = VersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(deb_format_error!(s, "empty"));
+        }
+
+        // Parse epoch
+        let (epoch, rest) = if let Some(colon) = s.find(':') {
+            let ep_str = &s[..colon];
+            if ep_str.is_empty() {
+                return Err(deb_format_error!(s, "missing epoch"));
+            }
+            let epoch = ep_str
+                .parse::<u64>()
+                .map_err(|e| deb_format_error!(s, format!("invalid epoch: {e}")))?;
+            (epoch, &s[colon + 1..])
+        } else {
+            (0, s)
+        };
+
+        if rest.ends_with('-') {
+            return Err(deb_format_error!(
+                s,
+                "trailing '-' with empty debian_revision"
+            ));
+        }
+
+        // Split upstream and debian revision at last '-'
+        let (upstream, debian_revision) = rest
+            .rfind('-')
+            .map(|idx| {
+                let (u, d) = rest.split_at(idx);
+                (u, &d[1..])
+            })
+            .unwrap_or((rest, ""));
+
+        // Validate upstream
+        if upstream.is_empty() {
+            return Err(deb_format_error!(s, "missing upstream_version"));
+        }
+
+        if !upstream.chars().next().unwrap().is_ascii_digit() {
+            return Err(deb_format_error!(
+                s,
+                "upstream_version must start with a digit"
+            ));
+        }
+
+        for ch in upstream.chars() {
+            if !ch.is_ascii_alphanumeric() && !matches!(ch, '.' | '+' | '-' | '~') {
+                return Err(deb_format_error!(
+                    s,
+                    format!("invalid character '{ch}' in upstream_version")
+                ));
+            }
+        }
+
+        // Validate debian_revision when present
+        if !debian_revision.is_empty() {
+            for ch in debian_revision.chars() {
+                if !ch.is_ascii_alphanumeric() && !matches!(ch, '+' | '.' | '~') {
+                    return Err(deb_format_error!(
+                        s,
+                        format!("invalid character '{ch}' in debian_revision")
+                    ));
+                }
+            }
+        }
+
+        Ok(DebVersion::from_parts(
+            epoch,
+            upstream.to_string(),
+            debian_revision.to_string(),
+        ))
+    }
+}
+
+impl Ord for DebVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+impl PartialOrd for DebVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Compare the keys rather than the fields, so that equality is consistent
+// with ordering: `1.01` equals `1.1`, and `1.0` equals `1.0-0`.
+impl PartialEq for DebVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Hash for DebVersion {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key.hash(state);
+    }
+}
+
+/// Append the comparison tokens of an upstream version or Debian revision.
+///
+/// dpkg compares a part as alternating non-digit and digit runs. Each run
+/// pair becomes the weights of its non-digit characters, an [`END`] marker
+/// and its number, and the part is closed by another [`END`]. A part that
+/// runs out compares like an empty non-digit run, so the closing marker
+/// sorts it after a `~` and before any other character in the same place.
+/// Only the first non-digit run can be empty, so the markers always line up.
+fn tokenize_part(part: &str, key: &mut Vec<Token>) {
+    let mut rest = part;
+    while !rest.is_empty() {
+        let non_digits = rest
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (non_digit, remainder) = rest.split_at(non_digits);
+        key.extend(
+            non_digit
+                .chars()
+                .map(|c| Token::NonDigit(non_digit_order(c))),
+        );
+        key.push(END);
+
+        let digits = remainder
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(remainder.len());
+        let (digit, remainder) = remainder.split_at(digits);
+        let digit = digit.trim_start_matches('0');
+        key.push(match digit.parse() {
+            Ok(number) => Token::Number(number),
+            Err(_) if digit.is_empty() => Token::Number(0),
+            Err(_) => Token::BigNumber(digit.len(), digit.into()),
+        });
+        rest = remainder;
+    }
+    key.push(END);
+}
+
+/// The sort weight of a non-digit character: `~` sorts before the [`END`]
+/// of a run, and letters by their code before all other characters.
+fn non_digit_order(c: char) -> u32 {
+    match c {
+        '~' => 0,
+        c if c.is_ascii_alphabetic() => c as u32 + 2,
+        c => c as u32 + 258,
+    }
+}
+
+/// A relation operator of `dpkg --compare-versions`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DpkgOperator {
+    /// `lt`, `<<`
+    Lt,
+    /// `le`, `<=`, and the deprecated `<`
+    Le,
+    /// `eq`, `=`
+    Eq,
+    /// `ne`
+    Ne,
+    /// `ge`, `>=`, and the deprecated `>`
+    Ge,
+    /// `gt`, `>>`
+    Gt,
+    /// `lt-nl`: like `lt`, but an empty version is newer than any other
+    LtNl,
+    /// `le-nl`: like `le`, but an empty version is newer than any other
+    LeNl,
+    /// `ge-nl`: like `ge`, but an empty version is newer than any other
+    GeNl,
+    /// `gt-nl`: like `gt`, but an empty version is newer than any other
+    GtNl,
+}
+
+impl DpkgOperator {
+    /// Evaluate `a op b`, where `None` is an empty version. An empty version
+    /// is older than any other, or newer for the `-nl` operators, and equal
+    /// to another empty version.
+    pub fn compare(self, a: Option<&DebVersion>, b: Option<&DebVersion>) -> bool {
+        let empty_is_newer = matches!(self, Self::LtNl | Self::LeNl | Self::GeNl | Self::GtNl);
+        let ordering = match (a, b) {
+            (Some(a), Some(b)) => a.cmp(b),
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) if empty_is_newer => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) if empty_is_newer => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+        };
+        match self {
+            Self::Lt | Self::LtNl => ordering.is_lt(),
+            Self::Le | Self::LeNl => ordering.is_le(),
+            Self::Eq => ordering.is_eq(),
+            Self::Ne => ordering.is_ne(),
+            Self::Ge | Self::GeNl => ordering.is_ge(),
+            Self::Gt | Self::GtNl => ordering.is_gt(),
+        }
+    }
+}
+
+impl FromStr for DpkgOperator {
+    type Err = VersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "lt" | "<<" => Self::Lt,
+            "le" | "<=" | "<" => Self::Le,
+            "eq" | "=" => Self::Eq,
+            "ne" => Self::Ne,
+            "ge" | ">=" | ">" => Self::Ge,
+            "gt" | ">>" => Self::Gt,
+            "lt-nl" => Self::LtNl,
+            "le-nl" => Self::LeNl,
+            "ge-nl" => Self::GeNl,
+            "gt-nl" => Self::GtNl,
+            _ => {
+                return Err(VersError::InvalidConstraint(format!(
+                    "unknown dpkg relation operator '{}'",
+                    s
+                )));
+            }
+        })
+    }
+}
+
+/// Compare two versions like `dpkg --compare-versions a op b`.
+///
+/// All operators of dpkg are supported, including the deprecated `<` and
+/// `>`, which mean `<=` and `>=`. An empty version, or `<unknown>`, is older
+/// than any other version, except for the `-nl` ("not less") operators, for
+/// which it is newer.
+///
+/// # Examples
+///
+/// ```
+/// use vers_rs::schemes::deb::compare_versions;
+///
+/// assert!(compare_versions("1.0~rc1", "lt", "1.0").unwrap());
+/// assert!(compare_versions("1:0.9", "gt", "2.0").unwrap());
+/// assert!(compare_versions("", "lt", "1.0").unwrap());
+/// assert!(!compare_versions("", "lt-nl", "1.0").unwrap());
+/// ```
+pub fn compare_versions(a: &str, op: &str, b: &str) -> Result<bool, VersError> {
+    let parse = |version: &str| match version {
+        "" | "<unknown>" => Ok(None),
+        _ => version.parse::<DebVersion>().map(Some),
+    };
+    let operator: DpkgOperator = op.parse()?;
+    Ok(operator.compare(parse(a)?.as_ref(), parse(b)?.as_ref()))
+}
+
+/// Which parts of Debian versions [`VersVersionRange::contains_with_mode`]
+/// compares.
+///
+/// Upstream advisories usually give bare upstream versions such as `1.2.3`,
+/// which sort before every version with an epoch, like an installed
+/// `1:1.2.3-4`. The relaxed modes drop parts of both the checked version and
+/// the range's versions before comparing them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DebMatchMode {
+    /// Compare whole versions.
+    #[default]
+    Full,
+    /// Ignore epochs, so `1:1.2.3-4` compares like `1.2.3-4`.
+    IgnoreEpoch,
+    /// Compare upstream versions only, so `1:1.2.3-4` compares like `1.2.3`.
+    UpstreamOnly,
+    /// Ignore Debian revisions, so `1:1.2.3-4` compares like `1:1.2.3`.
+    IgnoreRevision,
+}
+
+impl DebMatchMode {
+    /// The version with the parts this mode ignores removed.
+    pub fn apply(self, version: &DebVersion) -> DebVersion {
+        let (epoch, debian_revision) = match self {
+            Self::Full => return version.clone(),
+            Self::IgnoreEpoch => (0, version.debian_revision.clone()),
+            Self::UpstreamOnly => (0, String::new()),
+            Self::IgnoreRevision => (version.epoch, String::new()),
+        };
+        DebVersion::from_parts(epoch, version.upstream.clone(), debian_revision)
+    }
+}
+
+impl VersVersionRange<DebVersion> {
+    /// Check if a version is contained within this range, comparing only
+    /// the parts of the versions selected by `mode`.
+    ///
+    /// With [`DebMatchMode::Full`] this is the same as
+    /// [`VersionRange::contains`](crate::range::VersionRange::contains).
+    /// Otherwise the bounds of each interval of the range are reduced, and an
+    /// interval whose reduced bounds no longer enclose any version, like
+    /// `>=2.0-1|<<1:1.0-1` under [`DebMatchMode::UpstreamOnly`], matches
+    /// nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::VersVersionRange;
+    /// use vers_rs::schemes::deb::{DebMatchMode, DebVersion};
+    ///
+    /// let range: VersVersionRange<DebVersion> = "vers:deb/>=1.2.0|<<1.2.5".parse().unwrap();
+    /// let installed: DebVersion = "1:1.2.3-4".parse().unwrap();
+    /// assert!(!range.contains_with_mode(installed.clone(), DebMatchMode::Full).unwrap());
+    /// assert!(range.contains_with_mode(installed, DebMatchMode::IgnoreEpoch).unwrap());
+    /// ```
+    pub fn contains_with_mode(
+        &self,
+        version: DebVersion,
+        mode: DebMatchMode,
+    ) -> Result<bool, VersError> {
+        use crate::range::VersionRange;
+
+        if mode == DebMatchMode::Full {
+            return self.contains(version);
+        }
+
+        // Dropping parts can reorder the versions, e.g. `2.0` and `1:1.0`, so
+        // the bounds of each interval are reduced in place and an interval
+        // whose reduced bounds are inverted matches nothing.
+        let version = mode.apply(&version);
+        let reduce = |bound: &Option<Bound<DebVersion>>| {
+            bound.as_ref().map(|bound| Bound {
+                version: mode.apply(&bound.version),
+                inclusive: bound.inclusive,
+            })
+        };
+        Ok(IntervalSet::from_constraints(&self.constraints)
+            .intervals()
+            .iter()
+            .map(|interval| Interval::new(reduce(&interval.lower), reduce(&interval.upper)))
+            .any(|interval| !interval.is_empty() && interval.contains(&version)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Comparator;
+    use crate::VersError;
+    use crate::range::VersionRange;
+    use crate::range::dynamic::DynamicVersionRange;
+
+    #[test]
+    fn test_deb_to_native() {
+        for raw in [">=1.0|<<2.0", "=1:2.3-4", ">>1.0|<=2.0", "<<1.0|>=2.0"] {
+            let range = DynamicVersionRange::parse_native("deb", raw).unwrap();
+            assert_eq!(range.to_native().unwrap(), raw);
+        }
+
+        let range = DynamicVersionRange::parse_native("deb", ">>1.0|<<2.0~rc1").unwrap();
+        let native = range.to_native().unwrap();
+        assert_eq!(
+            DynamicVersionRange::parse_native("deb", &native).unwrap(),
+            range
+        );
+    }
+
+    #[test]
+    fn test_deb_to_native_unsupported_comparators() {
+        use crate::VersVersionRange;
+        use crate::schemes::deb::DebVersion;
+
+        let range: VersVersionRange<DebVersion> = "vers:deb/*".parse().unwrap();
+        assert!(matches!(
+            range.to_native(),
+            Err(VersError::InvalidConstraint(_))
+        ));
+
+        let mut range: VersVersionRange<DebVersion> = "vers:deb/>=1.0".parse().unwrap();
+        range.constraints[0].comparator = Comparator::NotEqual;
+        assert!(range.to_native().is_err());
+    }
+
+    #[test]
+    fn test_deb_version_accessors_and_builder() {
+        use crate::schemes::deb::DebVersion;
+
+        let version: DebVersion = "1:2.36-9ubuntu1.1".parse().unwrap();
+        assert_eq!(version.epoch(), 1);
+        assert_eq!(version.upstream(), "2.36");
+        assert_eq!(version.debian_revision(), Some("9ubuntu1.1"));
+        assert!(!version.is_native());
+        let version: DebVersion = "12.4+deb12u5".parse().unwrap();
+        assert_eq!(version.debian_revision(), None);
+        assert!(version.is_native());
+
+        let built = DebVersion::builder("2.36")
+            .epoch(1)
+            .debian_revision("9ubuntu1.1")
+            .build()
+            .unwrap();
+        assert_eq!(built.to_string(), "1:2.36-9ubuntu1.1");
+        let built = DebVersion::builder("1.0-beta")
+            .debian_revision("1")
+            .build()
+            .unwrap();
+        assert_eq!(built.upstream(), "1.0-beta");
+
+        for builder in [
+            DebVersion::builder("1.0-beta"),
+            DebVersion::builder(""),
+            DebVersion::builder("a1.0"),
+            DebVersion::builder("1.0").debian_revision("1_1"),
+        ] {
+            assert!(matches!(
+                builder.build(),
+                Err(VersError::InvalidVersionFormat(..))
+            ),);
+        }
+    }
+
+    #[test]
+    fn test_deb_compare_versions() {
+        use crate::schemes::deb::compare_versions;
+
+        for (a, op, b, expected) in [
+            ("1.0", "lt", "1.1", true),
+            ("1.0", "<<", "1.0", false),
+            ("1.0", "le", "1.0", true),
+            ("1.0", "<", "1.0", true),
+            ("1.0", ">", "1.0", true),
+            ("1.01", "eq", "1.1", true),
+            ("1.0", "=", "1.0-0", true),
+            ("1.0", "ne", "1.0-1", true),
+            ("1.0+b1", "gt", "1.0", true),
+            ("1.0a", "lt", "1.0+", true),
+            ("1.0~rc1", "ge", "1.0", false),
+            ("", "lt", "1.0", true),
+            ("", "eq", "<unknown>", true),
+            ("", "lt-nl", "1.0", false),
+            ("", "gt-nl", "1.0", true),
+            ("1.0", "le-nl", "", true),
+            ("1.0", "ge-nl", "", false),
+            ("1.0", "gt-nl", "0.9", true),
+        ] {
+            assert_eq!(
+                compare_versions(a, op, b).unwrap(),
+                expected,
+                "{a} {op} {b}"
+            );
+        }
+        assert!(matches!(
+            compare_versions("1.0", "!=", "1.1"),
+            Err(VersError::InvalidConstraint(_))
+        ));
+        assert!(compare_versions("a", "lt", "1.0").is_err());
+    }
+
+    #[test]
+    fn test_deb_version_hash_matches_eq() {
+        use crate::schemes::deb::DebVersion;
+        use std::collections::HashSet;
+
+        let set: HashSet<DebVersion> = ["1.01", "1.1", "1.1-0", "0:1.1", "1.1a", "1.1a0", "1.1-1"]
+            .iter()
+            .map(|v| v.parse().unwrap())
+            .collect();
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&"1.001-00".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_deb_version_key_ordering() {
+        use crate::schemes::deb::DebVersion;
+
+        let sorted = [
+            "0:1.0~~",
+            "1.0~~a",
+            "1.0~",
+            "1.0~1",
+            "1.0",
+            "1.0-0.1",
+            "1.0-1~bpo1",
+            "1.0-1",
+            "1.0-1+b1",
+            "1.0a",
+            "1.0+dfsg",
+            "1.0.0",
+            "1.0.99999999999999999999",
+            "1.0.100000000000000000000",
+            "1.1",
+            "1:0.1",
+        ];
+        let versions: Vec<DebVersion> = sorted.iter().map(|v| v.parse().unwrap()).collect();
+        for (i, a) in versions.iter().enumerate() {
+            for (j, b) in versions.iter().enumerate() {
+                assert_eq!(a.cmp(b), i.cmp(&j), "{a} vs {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_deb_version_serde_unchanged() {
+        use crate::schemes::deb::DebVersion;
+
+        let version: DebVersion = "1:2.36-9+deb12u7".parse().unwrap();
+        let json = serde_json::to_string(&version).unwrap();
+        assert_eq!(
+            json,
+            r#"{"epoch":1,"upstream":"2.36","debian_revision":"9+deb12u7"}"#
+        );
+        let parsed: DebVersion = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, version);
+        assert!(parsed < "1:2.36-10".parse().unwrap());
+    }
+
+    #[test]
+    fn test_deb_match_modes() {
+        use crate::VersVersionRange;
+        use crate::schemes::deb::{DebMatchMode, DebVersion};
+
+        let range: VersVersionRange<DebVersion> = "vers:deb/>=1.2.0|<<1.2.3".parse().unwrap();
+        let contains = |version: &str, mode| {
+            range
+                .contains_with_mode(version.parse().unwrap(), mode)
+                .unwrap()
+        };
+        // Any epoch sorts after the bare upstream bounds
+        assert!(!contains("1:1.2.1-1", DebMatchMode::Full));
+        assert!(contains("1:1.2.1-1", DebMatchMode::IgnoreEpoch));
+        assert!(contains("1:1.2.1-1", DebMatchMode::UpstreamOnly));
+        assert!(!contains("1:1.2.1-1", DebMatchMode::IgnoreRevision));
+
+        // The revision of a fixed upstream version
+        assert!(contains("1.2.2-5", DebMatchMode::Full));
+        assert!(!contains("1.2.3-1", DebMatchMode::IgnoreEpoch));
+        assert!(!contains("2:1.2.3-1", DebMatchMode::UpstreamOnly));
+        assert!(contains("1.2.3~rc1-1", DebMatchMode::IgnoreRevision));
+
+        // Bounds with epochs are reduced too, and may change their order:
+        // [2.0-1, 1:1.0-1) becomes [2.0, 1.0), which matches nothing
+        let range: VersVersionRange<DebVersion> = "vers:deb/>=2.0-1|<<1:1.0-1".parse().unwrap();
+        let contains = |version: &str, mode| {
+            range
+                .contains_with_mode(version.parse().unwrap(), mode)
+                .unwrap()
+        };
+        assert!(contains("2.5", DebMatchMode::Full));
+        assert!(!contains("0.5", DebMatchMode::Full));
+        for version in ["0.5", "1.0", "1:1.5", "2.0-1", "1:2.5-1"] {
+            assert!(!contains(version, DebMatchMode::UpstreamOnly), "{version}");
+        }
+    }
+
+    #[test]
+    fn test_dynamic_contains_deb() {
+        use crate::schemes::deb::DebMatchMode;
+
+        let range: DynamicVersionRange = "vers:deb/<<3.0.13".parse().unwrap();
+        assert!(
+            !range
+                .contains_deb("1:3.0.11-1".to_string(), DebMatchMode::Full)
+                .unwrap()
+        );
+        assert!(
+            range
+                .contains_deb("1:3.0.11-1".to_string(), DebMatchMode::IgnoreEpoch)
+                .unwrap()
+        );
+        let range: DynamicVersionRange = "vers:npm/<1.0.0".parse().unwrap();
+        assert!(matches!(
+            range.contains_deb("0.9".to_string(), DebMatchMode::Full),
+            Err(VersError::UnsupportedVersioningScheme(_))
+        ));
+    }
+
+    #[test]
+    fn test_dynamic_parse_deb() {
+        let range: DynamicVersionRange = "vers:deb/<<1.0".parse().unwrap();
+        assert_eq!(range.versioning_scheme(), "deb");
+        assert_eq!(range.constraints().len(), 1);
+        assert_eq!(range.constraints()[0].comparator, Comparator::LessThan);
+        assert_eq!(range.constraints()[0].version.to_string(), "1.0");
+    }
+
+    #[test]
+    fn test_deb_version_ordering_basic() {
+        let range: DynamicVersionRange = "vers:deb/<<1.0".parse().unwrap();
+        assert!(range.contains("0.9".to_string()).unwrap());
+        assert!(!range.contains("1.0".to_string()).unwrap());
+    }
+
+    #[test]
+    fn test_deb_version_ordering_tilde_and_epoch() {
+        // 1.0~beta < 1.0
+        let range1: DynamicVersionRange = "vers:deb/<<1.0".parse().unwrap();
+        assert!(range1.contains("1.0~beta".to_string()).unwrap());
+
+        let range2: DynamicVersionRange = "vers:deb/>>2.0".parse().unwrap();
+        // 1:1.0 > 2.0 because epoch 1 > 0
+        assert!(range2.contains("1:1.0".to_string()).unwrap());
+        assert!(!range2.contains("2.0".to_string()).unwrap());
+    }
+
+    #[test]
+    fn test_deb_valid_comparators() {
+        // << maps to LessThan
+        let range: DynamicVersionRange = "vers:deb/<<1.0".parse().unwrap();
+        assert_eq!(range.constraints()[0].comparator, Comparator::LessThan);
+
+        // <= maps to LessThanOrEqual
+        let range: DynamicVersionRange = "vers:deb/<=1.0".parse().unwrap();
+        assert_eq!(
+            range.constraints()[0].comparator,
+            Comparator::LessThanOrEqual
+        );
+
+        // = maps to Equal
+        let range: DynamicVersionRange = "vers:deb/=1.0".parse().unwrap();
+        assert_eq!(range.constraints()[0].comparator, Comparator::Equal);
+
+        // >= maps to GreaterThanOrEqual
+        let range: DynamicVersionRange = "vers:deb/>=1.0".parse().unwrap();
+        assert_eq!(
+            range.constraints()[0].comparator,
+            Comparator::GreaterThanOrEqual
+        );
+
+        // >> maps to GreaterThan
+        let range: DynamicVersionRange = "vers:deb/>>1.0".parse().unwrap();
+        assert_eq!(range.constraints()[0].comparator, Comparator::GreaterThan);
+    }
+
+    #[test]
+    fn test_deb_invalid_comparators_rejected() {
+        // Single < is not a valid Debian comparator
+        let result: Result<DynamicVersionRange, VersError> = "vers:deb/<1.0".parse();
+        assert!(result.is_err());
+
+        // Single > is not a valid Debian comparator
+        let result: Result<DynamicVersionRange, VersError> = "vers:deb/>1.0".parse();
+        assert!(result.is_err());
+
+        // != is not a valid Debian comparator
+        let result: Result<DynamicVersionRange, VersError> = "vers:deb/!=1.0".parse();
+        assert!(result.is_err());
+
+        // >>= is not a valid Debian comparator (>> with version "=1.0" fails)
+        let result: Result<DynamicVersionRange, VersError> = "vers:deb/>>=1.0".parse();
+        assert!(result.is_err());
+
+        // <<= is not a valid Debian comparator (<< with version "=1.0" fails)
+        let result: Result<DynamicVersionRange, VersError> = "vers:deb/<<=1.0".parse();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[allow(clippy::nonminimal_bool)]
+    fn test_deb_equality_consistent_with_ordering() {
+        use super::DebVersion;
+
+        // A version with empty debian_revision should equal one with "0"
+        let a = DebVersion::from_parts(0, "1.0".to_string(), String::new());
+        let b = DebVersion::from_parts(0, "1.0".to_string(), "0".to_string());
+
+        // Equality must be consistent with Ord::cmp
+        assert_eq!(a, b);
+        assert!(!(a < b));
+        assert!(!(a > b));
+    }
+
+    #[test]
+    fn test_deb_parse_native_preserves_scheme() {
+        let range = DynamicVersionRange::parse_native("deb", "<<1.0").unwrap();
+        assert_eq!(range.versioning_scheme(), "deb");
+    }
+
+    #[test]
+    fn test_deb_parse_native_normalizes() {
+        // parse_native should normalize: >1.0|>2.0 simplifies to >1.0
+        let range = DynamicVersionRange::parse_native("deb", ">>1.0|>>2.0").unwrap();
+        assert_eq!(range.constraints().len(), 1);
+        assert_eq!(range.constraints()[0].comparator, Comparator::GreaterThan);
+        assert_eq!(range.constraints()[0].version.to_string(), "1.0");
+    }
+
+    #[test]
+    fn test_deb_letters_sort_before_other_characters() {
+        use crate::schemes::deb::DebVersion;
+
+        // dpkg sorts letters before all other non-digit characters. These
+        // pairs used to be ordered by character code, which put '+' and '.'
+        // before letters.
+        for (lower, higher) in [
+            ("1.0a", "1.0+"),
+            ("1.0z", "1.0."),
+            ("1.0-1ubuntu1", "1.0-1+deb12u1"),
+            ("2.36a", "2.36+dfsg"),
+        ] {
+            let lower: DebVersion = lower.parse().unwrap();
+            let higher: DebVersion = higher.parse().unwrap();
+            assert!(lower < higher, "{lower} < {higher}");
+        }
+
+        // Orderings among letters, among other characters, and of '~' are
+        // unchanged
+        for (lower, higher) in [("1.0a", "1.0b"), ("1.0+", "1.0."), ("1.0~a", "1.0")] {
+            let lower: DebVersion = lower.parse().unwrap();
+            let higher: DebVersion = higher.parse().unwrap();
+            assert!(lower < higher, "{lower} < {higher}");
+        }
+    }
+}
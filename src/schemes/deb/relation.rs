@@ -0,0 +1,814 @@
+//! Debian package relationship fields.
+//!
+//! Fields like `Depends`, `Breaks`, `Conflicts` and `Provides` list package
+//! relations, e.g. `libc6 (>= 2.34), libssl3 (>= 3.0.0) | libssl1.1 [amd64]`.
+//! Commas separate requirements that must all hold, and `|` separates
+//! alternatives of which one suffices. This module parses such fields into
+//! a [`Relationship`] and evaluates them against a [`PackageSet`] of
+//! installed packages.
+
+use super::{DebVersion, debian_operator};
+use crate::comparator::Comparator;
+use crate::constraint::NativeVersionConverter;
+use crate::range::interval::{Bound, IntervalSet};
+use crate::{VersError, VersVersionRange, VersionConstraint};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// A parsed relationship field: a conjunction of groups of alternatives.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Relationship {
+    /// The comma-separated requirements, each a list of `|`-separated
+    /// alternatives
+    pub groups: Vec<Vec<Relation>>,
+}
+
+/// A single package relation like `libssl1.1:amd64 (>= 1.1.1) [amd64] <!nocheck>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Relation {
+    /// The package name
+    pub package: String,
+    /// The architecture qualifier after `:`, such as `any`, `native` or `amd64`
+    pub arch_qualifier: Option<String>,
+    /// The version relation in parentheses
+    pub version: Option<VersionConstraint<DebVersion>>,
+    /// The architecture restriction list in brackets
+    pub architectures: Vec<Restriction>,
+    /// The build profile restriction formulas, one per `<...>` group
+    pub profiles: Vec<Vec<Restriction>>,
+}
+
+/// A possibly negated architecture or build profile name in a restriction list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Restriction {
+    /// Whether the name is prefixed with `!`
+    pub negated: bool,
+    /// The architecture (or wildcard like `linux-any`) or build profile name
+    pub name: String,
+}
+
+/// The host architecture and active build profiles that restriction lists
+/// are evaluated against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Environment {
+    /// The Debian architecture, e.g. `amd64`
+    pub architecture: String,
+    /// The active build profiles, e.g. `nocheck`
+    pub profiles: Vec<String>,
+}
+
+impl Environment {
+    /// Create an environment for `architecture` without active build profiles.
+    pub fn new(architecture: &str) -> Self {
+        Environment {
+            architecture: architecture.to_string(),
+            profiles: Vec::new(),
+        }
+    }
+}
+
+/// A package that satisfies relations on a name: either an installed
+/// package, or a virtual package provided by one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Candidate {
+    version: Option<DebVersion>,
+    architecture: String,
+}
+
+/// The installed packages a [`Relationship`] is evaluated against.
+///
+/// Multi-Arch is not modelled: a relation without architecture qualifier is
+/// satisfied by a package of any architecture.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PackageSet {
+    packages: HashMap<String, Vec<Candidate>>,
+}
+
+impl PackageSet {
+    /// Create an empty package set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an installed package.
+    pub fn insert(&mut self, package: &str, version: DebVersion, architecture: &str) {
+        self.packages
+            .entry(package.to_string())
+            .or_default()
+            .push(Candidate {
+                version: Some(version),
+                architecture: architecture.to_string(),
+            });
+    }
+
+    /// Add the virtual packages of an installed package's `Provides` field.
+    ///
+    /// An unversioned entry like `mail-transport-agent` only satisfies
+    /// unversioned relations, while `libfoo-abi (= 2)` also satisfies
+    /// versioned ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VersError::InvalidConstraint`] if the field contains
+    /// alternatives or a version relation other than `=`, which `Provides`
+    /// does not allow.
+    pub fn insert_provides(
+        &mut self,
+        provides: &Relationship,
+        architecture: &str,
+    ) -> Result<(), VersError> {
+        for group in &provides.groups {
+            let [relation] = group.as_slice() else {
+                return Err(VersError::InvalidConstraint(format!(
+                    "alternatives are not allowed in Provides: '{}'",
+                    provides
+                )));
+            };
+            let version = match &relation.version {
+                None => None,
+                Some(constraint) if constraint.comparator == Comparator::Equal => {
+                    Some(constraint.version.clone())
+                }
+                Some(_) => {
+                    return Err(VersError::InvalidConstraint(format!(
+                        "only '=' relations are allowed in Provides: '{}'",
+                        relation
+                    )));
+                }
+            };
+            self.packages
+                .entry(relation.package.clone())
+                .or_default()
+                .push(Candidate {
+                    version,
+                    architecture: architecture.to_string(),
+                });
+        }
+        Ok(())
+    }
+}
+
+impl Relationship {
+    /// The requirements on `package` that hold for exactly the versions in
+    /// `range`, in the form of a `Depends` field.
+    ///
+    /// Each gap between the intervals of the range becomes a requirement
+    /// with two alternatives, so `>=1.0|<<2.0|>=3.0` gives
+    /// `libfoo (>= 1.0), libfoo (<< 2.0) | libfoo (>= 3.0)`. A range with a
+    /// single version gives `libfoo (= 1.0)`, and a range matching every
+    /// version the unversioned `libfoo`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VersError::InvalidRange`] if the range matches no version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::VersVersionRange;
+    /// use vers_rs::schemes::deb::DebVersion;
+    /// use vers_rs::schemes::deb::relation::Relationship;
+    ///
+    /// let range: VersVersionRange<DebVersion> = "vers:deb/>=1.0|<<2.0".parse().unwrap();
+    /// let depends = Relationship::from_range("libfoo", &range).unwrap();
+    /// assert_eq!(depends.to_string(), "libfoo (>= 1.0), libfoo (<< 2.0)");
+    /// ```
+    pub fn from_range(
+        package: &str,
+        range: &VersVersionRange<DebVersion>,
+    ) -> Result<Self, VersError> {
+        let set = IntervalSet::from_constraints(&range.constraints);
+        let relation = |version| Relation {
+            package: package.to_string(),
+            arch_qualifier: None,
+            version,
+            architectures: Vec::new(),
+            profiles: Vec::new(),
+        };
+        let bound_relation = |bound: &Bound<DebVersion>, inclusive, exclusive| {
+            let comparator = if bound.inclusive {
+                inclusive
+            } else {
+                exclusive
+            };
+            relation(Some(VersionConstraint::new(
+                comparator,
+                bound.version.clone(),
+            )))
+        };
+
+        if set.is_empty() {
+            return Err(VersError::InvalidRange(format!(
+                "'{}' does not match any version",
+                range
+            )));
+        }
+        if let [interval] = set.intervals()
+            && let (Some(lower), Some(upper)) = (&interval.lower, &interval.upper)
+            && lower == upper
+        {
+            let equal = VersionConstraint::new(Comparator::Equal, lower.version.clone());
+            return Ok(Relationship {
+                groups: vec![vec![relation(Some(equal))]],
+            });
+        }
+
+        // The intervals are sorted and disjoint, so the range is the lower
+        // bound of the first one, the upper bound of the last one, and for
+        // each gap "below the previous interval's end or above the next
+        // interval's start".
+        let mut groups = Vec::new();
+        let mut group = Vec::new();
+        for interval in set.intervals() {
+            if let Some(lower) = &interval.lower {
+                group.push(bound_relation(
+                    lower,
+                    Comparator::GreaterThanOrEqual,
+                    Comparator::GreaterThan,
+                ));
+                groups.push(std::mem::take(&mut group));
+            }
+            if let Some(upper) = &interval.upper {
+                group.push(bound_relation(
+                    upper,
+                    Comparator::LessThanOrEqual,
+                    Comparator::LessThan,
+                ));
+            }
+        }
+        if !group.is_empty() {
+            groups.push(group);
+        }
+        if groups.is_empty() {
+            groups.push(vec![relation(None)]);
+        }
+        Ok(Relationship { groups })
+    }
+
+    /// Check whether every requirement is satisfied by `packages`.
+    ///
+    /// Alternatives excluded by their restriction lists are ignored, and a
+    /// requirement whose alternatives are all excluded always holds. This is
+    /// the semantics of `Depends` and `Pre-Depends`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::schemes::deb::relation::{Environment, PackageSet, Relationship};
+    ///
+    /// let depends: Relationship = "libc6 (>= 2.34), libssl3 | libssl1.1".parse().unwrap();
+    /// let mut installed = PackageSet::new();
+    /// installed.insert("libc6", "2.36-9".parse().unwrap(), "amd64");
+    /// installed.insert("libssl1.1", "1.1.1n-0+deb11u5".parse().unwrap(), "amd64");
+    /// assert!(depends.is_satisfied_by(&installed, &Environment::new("amd64")));
+    /// ```
+    pub fn is_satisfied_by(&self, packages: &PackageSet, environment: &Environment) -> bool {
+        self.groups.iter().all(|group| {
+            let mut active = group.iter().filter(|r| r.is_active(environment)).peekable();
+            active.peek().is_none() || active.any(|r| r.is_satisfied_by(packages, environment))
+        })
+    }
+
+    /// The relations satisfied by `packages`, ignoring alternatives excluded
+    /// by their restriction lists.
+    ///
+    /// For `Breaks` and `Conflicts`, these are the offending packages: the
+    /// field is violated if any relation is satisfied.
+    pub fn satisfied_relations<'a>(
+        &'a self,
+        packages: &'a PackageSet,
+        environment: &'a Environment,
+    ) -> impl Iterator<Item = &'a Relation> + 'a {
+        self.groups
+            .iter()
+            .flatten()
+            .filter(move |r| r.is_active(environment) && r.is_satisfied_by(packages, environment))
+    }
+}
+
+impl Relation {
+    /// Check whether the architecture and build profile restrictions of this
+    /// relation include `environment`.
+    pub fn is_active(&self, environment: &Environment) -> bool {
+        let architecture_matches = if self.architectures.is_empty() {
+            true
+        } else if self.architectures.iter().all(|r| r.negated) {
+            !self
+                .architectures
+                .iter()
+                .any(|r| arch_matches(&r.name, &environment.architecture))
+        } else {
+            self.architectures
+                .iter()
+                .any(|r| !r.negated && arch_matches(&r.name, &environment.architecture))
+        };
+
+        // Profile formulas are alternatives of conjunctions
+        let profiles_match = self.profiles.is_empty()
+            || self.profiles.iter().any(|terms| {
+                terms
+                    .iter()
+                    .all(|term| environment.profiles.contains(&term.name) != term.negated)
+            });
+
+        architecture_matches && profiles_match
+    }
+
+    /// Check whether an installed or provided package satisfies this
+    /// relation, ignoring restriction lists.
+    pub fn is_satisfied_by(&self, packages: &PackageSet, environment: &Environment) -> bool {
+        let Some(candidates) = packages.packages.get(&self.package) else {
+            return false;
+        };
+        candidates.iter().any(|candidate| {
+            let architecture_matches = match self.arch_qualifier.as_deref() {
+                None | Some("any") => true,
+                Some(_) if candidate.architecture == "all" => true,
+                Some("native") => candidate.architecture == environment.architecture,
+                Some(qualifier) => candidate.architecture == qualifier,
+            };
+            let version_matches = match (&self.version, &candidate.version) {
+                (None, _) => true,
+                (Some(_), None) => false,
+                (Some(constraint), Some(version)) => {
+                    IntervalSet::from_constraint(constraint).contains(version)
+                }
+            };
+            architecture_matches && version_matches
+        })
+    }
+}
+
+/// Check whether a Debian architecture matches an architecture or wildcard
+/// like `any`, `linux-any` or `any-amd64`.
+///
+/// As in dpkg, a wildcard names the trailing parts of the
+/// `abi-libc-os-cpu` tuple of an architecture, so `any-arm` matches `armhf`
+/// and `armel`, and `gnu-any-any` matches every glibc architecture.
+fn arch_matches(pattern: &str, architecture: &str) -> bool {
+    if pattern == architecture || pattern == "any" {
+        return true;
+    }
+    let parts: Vec<&str> = pattern.split('-').collect();
+    if parts.len() > 4 || !parts.contains(&"any") {
+        return false;
+    }
+    arch_tuple(architecture).is_some_and(|tuple| {
+        parts
+            .iter()
+            .rev()
+            .zip(tuple.iter().rev())
+            .all(|(part, name)| *part == "any" || part == name)
+    })
+}
+
+/// The `abi-libc-os-cpu` tuple of a Debian architecture, following dpkg's
+/// tupletable for the Linux, Hurd, kFreeBSD and BSD architectures.
+fn arch_tuple(architecture: &str) -> Option<[&str; 4]> {
+    let tuple = match architecture {
+        "armhf" => ["eabihf", "gnu", "linux", "arm"],
+        "armel" => ["eabi", "gnu", "linux", "arm"],
+        "x32" => ["x32", "gnu", "linux", "amd64"],
+        "powerpcspe" => ["spe", "gnu", "linux", "powerpc"],
+        "mips64" | "mips64el" | "mips64r6" | "mips64r6el" => {
+            ["abi64", "gnu", "linux", architecture]
+        }
+        "musl-linux-armhf" => ["eabihf", "musl", "linux", "arm"],
+        "musl-linux-armel" => ["eabi", "musl", "linux", "arm"],
+        _ => {
+            if let Some(cpu) = architecture.strip_prefix("musl-linux-") {
+                ["base", "musl", "linux", cpu]
+            } else if let Some((os, cpu)) = architecture.split_once('-') {
+                let libc = match os {
+                    "hurd" | "kfreebsd" => "gnu",
+                    "darwin" | "freebsd" | "netbsd" | "openbsd" => "bsd",
+                    _ => return None,
+                };
+                ["base", libc, os, cpu]
+            } else {
+                ["base", "gnu", "linux", architecture]
+            }
+        }
+    };
+    Some(tuple)
+}
+
+impl FromStr for Relationship {
+    type Err = VersError;
+
+    /// Parse a relationship field. Empty entries, e.g. from a trailing comma,
+    /// are skipped, and an empty field has no requirements.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let groups = s
+            .split(',')
+            .filter(|group| !group.trim().is_empty())
+            .map(|group| group.split('|').map(str::parse).collect())
+            .collect::<Result<_, _>>()?;
+        Ok(Relationship { groups })
+    }
+}
+
+impl FromStr for Relation {
+    type Err = VersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |msg: &str| {
+            VersError::InvalidConstraint(format!(
+                "invalid package relation '{}': {}",
+                s.trim(),
+                msg
+            ))
+        };
+        let is_name_char = |c: char| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.');
+
+        let mut rest = s.trim();
+        let name_len = rest.find(|c| !is_name_char(c)).unwrap_or(rest.len());
+        let (package, remainder) = rest.split_at(name_len);
+        if package.is_empty() {
+            return Err(error("missing package name"));
+        }
+        if package.starts_with(['+', '-', '.']) || package.chars().any(|c| c.is_ascii_uppercase()) {
+            return Err(error("invalid package name"));
+        }
+        rest = remainder;
+
+        let mut arch_qualifier = None;
+        if let Some(remainder) = rest.strip_prefix(':') {
+            let len = remainder
+                .find(|c| !is_name_char(c))
+                .unwrap_or(remainder.len());
+            if len == 0 {
+                return Err(error("missing architecture qualifier"));
+            }
+            arch_qualifier = Some(remainder[..len].to_string());
+            rest = &remainder[len..];
+        }
+
+        let mut version = None;
+        rest = rest.trim_start();
+        if let Some(remainder) = rest.strip_prefix('(') {
+            let (inner, remainder) = remainder
+                .split_once(')')
+                .ok_or_else(|| error("missing ')'"))?;
+            version = Some(DebVersion::from_native_constraint(inner)?);
+            rest = remainder.trim_start();
+        }
+
+        let mut architectures = Vec::new();
+        if let Some(remainder) = rest.strip_prefix('[') {
+            let (inner, remainder) = remainder
+                .split_once(']')
+                .ok_or_else(|| error("missing ']'"))?;
+            architectures =
+                parse_restrictions(inner).ok_or_else(|| error("empty architecture list"))?;
+            rest = remainder.trim_start();
+        }
+
+        let mut profiles = Vec::new();
+        while let Some(remainder) = rest.strip_prefix('<') {
+            let (inner, remainder) = remainder
+                .split_once('>')
+                .ok_or_else(|| error("missing '>'"))?;
+            profiles
+                .push(parse_restrictions(inner).ok_or_else(|| error("empty build profile list"))?);
+            rest = remainder.trim_start();
+        }
+
+        if !rest.is_empty() {
+            return Err(error(&format!("unexpected '{}'", rest)));
+        }
+        Ok(Relation {
+            package: package.to_string(),
+            arch_qualifier,
+            version,
+            architectures,
+            profiles,
+        })
+    }
+}
+
+/// Parse a whitespace-separated list of possibly negated names, returning
+/// `None` if the list is empty.
+fn parse_restrictions(list: &str) -> Option<Vec<Restriction>> {
+    let restrictions: Vec<Restriction> = list
+        .split_whitespace()
+        .map(|term| match term.strip_prefix('!') {
+            Some(name) => Restriction {
+                negated: true,
+                name: name.to_string(),
+            },
+            None => Restriction {
+                negated: false,
+                name: term.to_string(),
+            },
+        })
+        .collect();
+    (!restrictions.is_empty() && restrictions.iter().all(|r| !r.name.is_empty()))
+        .then_some(restrictions)
+}
+
+impl fmt::Display for Relationship {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, group) in self.groups.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            for (j, relation) in group.iter().enumerate() {
+                if j > 0 {
+                    write!(f, " | ")?;
+                }
+                write!(f, "{}", relation)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Relation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.package)?;
+        if let Some(qualifier) = &self.arch_qualifier {
+            write!(f, ":{}", qualifier)?;
+        }
+        if let Some(constraint) = &self.version {
+            match debian_operator(constraint.comparator) {
+                Some(operator) => write!(f, " ({} {})", operator, constraint.version)?,
+                None => write!(f, " ({} {})", constraint.comparator, constraint.version)?,
+            }
+        }
+        if !self.architectures.is_empty() {
+            write!(f, " [{}]", join_restrictions(&self.architectures))?;
+        }
+        for terms in &self.profiles {
+            write!(f, " <{}>", join_restrictions(terms))?;
+        }
+        Ok(())
+    }
+}
+
+fn join_restrictions(restrictions: &[Restriction]) -> String {
+    restrictions
+        .iter()
+        .map(|r| format!("{}{}", if r.negated { "!" } else { "" }, r.name))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Comparator, Environment, PackageSet, Relation, Relationship, arch_matches};
+    use crate::VersError;
+
+    fn installed() -> PackageSet {
+        let mut packages = PackageSet::new();
+        packages.insert("libc6", "2.36-9+deb12u4".parse().unwrap(), "amd64");
+        packages.insert("libssl3", "3.0.11-1~deb12u2".parse().unwrap(), "amd64");
+        packages.insert("tzdata", "2024a-0+deb12u1".parse().unwrap(), "all");
+        packages.insert("postfix", "3.7.10-0+deb12u1".parse().unwrap(), "amd64");
+        packages
+            .insert_provides(
+                &"mail-transport-agent, default-mta (= 3.7)".parse().unwrap(),
+                "amd64",
+            )
+            .unwrap();
+        packages
+    }
+
+    #[test]
+    fn test_parse_relationship() {
+        let field = "libc6 (>= 2.34), libssl3 (>= 3.0.0) | libssl1.1 [amd64]";
+        let relationship: Relationship = field.parse().unwrap();
+        assert_eq!(relationship.groups.len(), 2);
+        assert_eq!(relationship.groups[1].len(), 2);
+
+        let libc = &relationship.groups[0][0];
+        assert_eq!(libc.package, "libc6");
+        let constraint = libc.version.as_ref().unwrap();
+        assert_eq!(constraint.comparator, Comparator::GreaterThanOrEqual);
+        assert_eq!(constraint.version.to_string(), "2.34");
+        assert_eq!(relationship.groups[1][1].architectures[0].name, "amd64");
+        assert_eq!(relationship.to_string(), field);
+
+        // Whitespace is optional and normalized on display
+        let relationship: Relationship = "foo(<<1.0)|bar,baz,".parse().unwrap();
+        assert_eq!(relationship.to_string(), "foo (<< 1.0) | bar, baz");
+        assert!("".parse::<Relationship>().unwrap().groups.is_empty());
+    }
+
+    #[test]
+    fn test_parse_qualifiers() {
+        let relation: Relation =
+            "python3:any (>= 3.11~) [linux-any !hurd-i386] <!nocheck> <stage1 cross>"
+                .parse()
+                .unwrap();
+        assert_eq!(relation.arch_qualifier.as_deref(), Some("any"));
+        assert_eq!(relation.architectures.len(), 2);
+        assert!(relation.architectures[1].negated);
+        assert_eq!(relation.profiles.len(), 2);
+        assert!(relation.profiles[0][0].negated);
+        assert_eq!(relation.profiles[1][1].name, "cross");
+        assert_eq!(
+            relation.to_string(),
+            "python3:any (>= 3.11~) [linux-any !hurd-i386] <!nocheck> <stage1 cross>"
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_relations() {
+        for raw in [
+            "libc6 (> 2.34)",
+            "libc6 (>= 2.34",
+            "libc6 (>= )",
+            "libc6 []",
+            "libc6 <>",
+            "libc6 foo",
+            "Libc6",
+            "foo | | bar",
+            "foo:",
+        ] {
+            assert!(
+                matches!(
+                    raw.parse::<Relationship>(),
+                    Err(VersError::InvalidConstraint(_))
+                ),
+                "{raw}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_evaluate_relationship() {
+        let packages = installed();
+        let amd64 = Environment::new("amd64");
+        let satisfied = |field: &str| {
+            field
+                .parse::<Relationship>()
+                .unwrap()
+                .is_satisfied_by(&packages, &amd64)
+        };
+
+        assert!(satisfied("libc6 (>= 2.34), libssl3 (>= 3.0.0) | libssl1.1"));
+        assert!(satisfied("libssl1.1 | libssl3 (>> 3.0.11-1~deb12u1)"));
+        assert!(!satisfied("libc6 (>= 2.37)"));
+        assert!(!satisfied("libc6, libssl1.1"));
+        assert!(satisfied("libc6:amd64 (= 2.36-9+deb12u4), tzdata:native"));
+        assert!(!satisfied("libc6:i386"));
+
+        // Virtual packages
+        assert!(satisfied("mail-transport-agent"));
+        assert!(!satisfied("mail-transport-agent (>= 1)"));
+        assert!(satisfied("default-mta (>= 3.7)"));
+        assert!(!satisfied("default-mta (>> 3.7)"));
+
+        let mut packages = PackageSet::new();
+        assert!(
+            packages
+                .insert_provides(&"foo (>= 1)".parse().unwrap(), "amd64")
+                .is_err()
+        );
+        assert!(
+            packages
+                .insert_provides(&"foo | bar".parse().unwrap(), "amd64")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_evaluate_restrictions() {
+        let packages = installed();
+        let environment = |architecture: &str, profiles: &[&str]| Environment {
+            architecture: architecture.to_string(),
+            profiles: profiles.iter().map(|p| p.to_string()).collect(),
+        };
+        let satisfied = |field: &str, environment: &Environment| {
+            field
+                .parse::<Relationship>()
+                .unwrap()
+                .is_satisfied_by(&packages, environment)
+        };
+
+        let field = "libfoo [!amd64], libtest <!nocheck>";
+        assert!(!satisfied(field, &environment("amd64", &[])));
+        assert!(satisfied(field, &environment("amd64", &["nocheck"])));
+        assert!(!satisfied(field, &environment("arm64", &["nocheck"])));
+
+        assert!(satisfied("libfoo [any-amd64]", &environment("arm64", &[])));
+        assert!(!satisfied("libfoo [linux-any]", &environment("arm64", &[])));
+        assert!(satisfied(
+            "libfoo [linux-any]",
+            &environment("hurd-i386", &[])
+        ));
+
+        // Only the active alternatives of a group count
+        let field = "libfoo [arm64] | libc6";
+        assert!(satisfied(field, &environment("arm64", &[])));
+
+        // Profile formulas: (stage1 and cross) or nocheck
+        let field = "libtest <stage1 cross> <nocheck>";
+        assert!(satisfied(field, &environment("amd64", &["cross"])));
+        assert!(!satisfied(
+            field,
+            &environment("amd64", &["cross", "stage1"])
+        ));
+        assert!(!satisfied(field, &environment("amd64", &["nocheck"])));
+
+        let amd64 = Environment::new("amd64");
+        let breaks: Relationship = "libssl3 (<< 3.0.12), postfix (<< 3.5) [linux-any], foo"
+            .parse()
+            .unwrap();
+        let offending: Vec<_> = breaks
+            .satisfied_relations(&packages, &amd64)
+            .map(|r| r.package.as_str())
+            .collect();
+        assert_eq!(offending, ["libssl3"]);
+    }
+
+    #[test]
+    fn test_architecture_wildcards() {
+        for (pattern, architecture, matches) in [
+            ("any-arm", "armhf", true),
+            ("any-arm", "armel", true),
+            ("any-arm", "arm64", false),
+            ("any-amd64", "x32", true),
+            ("any-amd64", "musl-linux-amd64", true),
+            ("any-amd64", "kfreebsd-amd64", true),
+            ("linux-any", "musl-linux-amd64", true),
+            ("linux-any", "hurd-amd64", false),
+            ("musl-any-any", "musl-linux-amd64", true),
+            ("musl-any-any", "amd64", false),
+            ("gnu-any-any", "hurd-i386", true),
+            ("eabihf-any-any-any", "armhf", true),
+            ("eabihf-any-any-any", "armel", false),
+            ("any-mips64el", "mips64el", true),
+            // Names that are not wildcards only match themselves
+            ("arm", "armhf", false),
+            ("linux-amd64", "amd64", false),
+        ] {
+            assert_eq!(
+                arch_matches(pattern, architecture),
+                matches,
+                "{pattern} {architecture}"
+            );
+        }
+
+        let relation: Relation = "libfoo [any-arm]".parse().unwrap();
+        assert!(relation.is_active(&Environment::new("armhf")));
+        assert!(!relation.is_active(&Environment::new("arm64")));
+    }
+
+    #[test]
+    fn test_relationship_from_range() {
+        use crate::VersVersionRange;
+        use crate::range::VersionRange;
+        use crate::schemes::deb::DebVersion;
+
+        for (raw, expected) in [
+            ("vers:deb/>=1.0|<<2.0", "libfoo (>= 1.0), libfoo (<< 2.0)"),
+            (
+                "vers:deb/>=1.0|<<2.0|>=3.0",
+                "libfoo (>= 1.0), libfoo (<< 2.0) | libfoo (>= 3.0)",
+            ),
+            (
+                "vers:deb/<<1.0|>>1.5|<=2.0|>>3.0",
+                "libfoo (<< 1.0) | libfoo (>> 1.5), libfoo (<= 2.0) | libfoo (>> 3.0)",
+            ),
+            ("vers:deb/=1:2.3-4", "libfoo (= 1:2.3-4)"),
+            ("vers:deb/*", "libfoo"),
+        ] {
+            let range: VersVersionRange<DebVersion> = raw.parse().unwrap();
+            let depends = Relationship::from_range("libfoo", &range).unwrap();
+            assert_eq!(depends.to_string(), expected, "{raw}");
+            assert_eq!(expected.parse::<Relationship>().unwrap(), depends);
+
+            // The field holds for exactly the versions in the range
+            for version in [
+                "0.5", "1.0", "1.5", "1.7", "2.0", "2.5", "3.0", "4.0", "1:2.3-4",
+            ] {
+                let version: DebVersion = version.parse().unwrap();
+                let mut packages = PackageSet::new();
+                packages.insert("libfoo", version.clone(), "amd64");
+                assert_eq!(
+                    depends.is_satisfied_by(&packages, &Environment::new("amd64")),
+                    range.contains(version.clone()).unwrap(),
+                    "{raw} {version}"
+                );
+            }
+        }
+
+        let range = VersVersionRange::new(
+            "deb".to_string(),
+            vec![
+                crate::VersionConstraint::new(
+                    Comparator::GreaterThan,
+                    "2.0".parse::<DebVersion>().unwrap(),
+                ),
+                crate::VersionConstraint::new(Comparator::LessThan, "1.0".parse().unwrap()),
+            ],
+        );
+        assert!(matches!(
+            Relationship::from_range("libfoo", &range),
+            Err(VersError::InvalidRange(_))
+        ));
+    }
+}
@@ -0,0 +1,326 @@
+//! Inventory of installed Debian packages.
+//!
+//! dpkg records installed packages in `/var/lib/dpkg/status`, a list of
+//! control paragraphs separated by blank lines. Distroless images have no
+//! dpkg database and instead ship one paragraph per package in files under
+//! `/var/lib/dpkg/status.d/`. This module reads both into a list of
+//! [`InstalledPackage`]s and matches them against vers ranges.
+
+use super::DebVersion;
+use crate::range::VersionRange;
+use crate::{VersError, VersVersionRange};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// A package recorded as installed in a dpkg status database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstalledPackage {
+    /// The binary package name
+    pub package: String,
+    /// The version of the binary package
+    pub version: DebVersion,
+    /// The architecture, e.g. `amd64` or `all`
+    pub architecture: String,
+    /// The source package name, which defaults to the binary package name
+    pub source: String,
+    /// The source package version, which defaults to the binary version
+    pub source_version: DebVersion,
+}
+
+/// Parse the contents of a dpkg status file.
+///
+/// Paragraphs whose `Status` field does not end in `installed`, such as
+/// removed packages with remaining configuration files, are skipped.
+/// Paragraphs without a `Status` field, as found in `status.d`, are treated
+/// as installed.
+///
+/// # Errors
+///
+/// Returns [`VersError::InvalidData`] if a paragraph lacks a `Package`,
+/// `Version` or `Architecture` field, and a version parsing error for an
+/// invalid version.
+///
+/// # Examples
+///
+/// ```
+/// use vers_rs::schemes::deb::status::parse_status;
+///
+/// let status = "Package: libssl3\n\
+///               Status: install ok installed\n\
+///               Architecture: amd64\n\
+///               Source: openssl\n\
+///               Version: 3.0.11-1~deb12u2\n";
+/// let packages = parse_status(status).unwrap();
+/// assert_eq!(packages[0].source, "openssl");
+/// assert_eq!(packages[0].version.to_string(), "3.0.11-1~deb12u2");
+/// ```
+pub fn parse_status(content: &str) -> Result<Vec<InstalledPackage>, VersError> {
+    let mut packages = Vec::new();
+    for paragraph in paragraphs(content) {
+        if let Some(package) = parse_paragraph(&paragraph)? {
+            packages.push(package);
+        }
+    }
+    Ok(packages)
+}
+
+/// Read a dpkg status file such as `/var/lib/dpkg/status`.
+///
+/// See [`parse_status`].
+pub fn read_status_file(path: impl AsRef<Path>) -> Result<Vec<InstalledPackage>, VersError> {
+    let path = path.as_ref();
+    let content = fs::read_to_string(path)
+        .map_err(|e| VersError::Io(format!("{}: {}", path.display(), e)))?;
+    parse_status(&content)
+}
+
+/// Read a `status.d` directory as used by distroless images.
+///
+/// Every file in the directory is parsed like a status file, except for
+/// the `.md5sums` files listing package contents. Packages are returned in
+/// file name order.
+pub fn read_status_dir(path: impl AsRef<Path>) -> Result<Vec<InstalledPackage>, VersError> {
+    let path = path.as_ref();
+    let io_error = |e: std::io::Error| VersError::Io(format!("{}: {}", path.display(), e));
+
+    let mut files = fs::read_dir(path)
+        .map_err(io_error)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(io_error)?;
+    files.retain(|file| file.is_file() && file.extension().is_none_or(|ext| ext != "md5sums"));
+    files.sort();
+
+    let mut packages = Vec::new();
+    for file in files {
+        packages.extend(read_status_file(file)?);
+    }
+    Ok(packages)
+}
+
+/// Find the installed packages whose version lies within a range.
+///
+/// `ranges` maps package names to affected version ranges. A package is
+/// affected if its binary package name is mapped to a range containing its
+/// version, or if its source package name is mapped to a range containing
+/// the source version, since Debian advisories are usually issued per source
+/// package.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use vers_rs::schemes::deb::status::{affected_packages, parse_status};
+///
+/// let packages = parse_status(
+///     "Package: libssl3\nArchitecture: amd64\nSource: openssl\nVersion: 3.0.11-1~deb12u2\n",
+/// )
+/// .unwrap();
+/// let ranges = HashMap::from([("openssl".to_string(), "vers:deb/<<3.0.13-1~deb12u1".parse().unwrap())]);
+/// let affected = affected_packages(&packages, &ranges).unwrap();
+/// assert_eq!(affected[0].0.package, "libssl3");
+/// ```
+pub fn affected_packages<'a>(
+    packages: &'a [InstalledPackage],
+    ranges: &'a HashMap<String, VersVersionRange<DebVersion>>,
+) -> Result<Vec<(&'a InstalledPackage, &'a VersVersionRange<DebVersion>)>, VersError> {
+    let mut affected = Vec::new();
+    for package in packages {
+        let candidates = [
+            (&package.package, &package.version),
+            (&package.source, &package.source_version),
+        ];
+        for (name, version) in candidates {
+            if let Some(range) = ranges.get(name)
+                && range.contains(version.clone())?
+            {
+                affected.push((package, range));
+                break;
+            }
+        }
+    }
+    Ok(affected)
+}
+
+/// Split control data into paragraphs of `(field, value)` pairs. Field names
+/// are lowercased, since they are case-insensitive, and continuation lines
+/// are dropped, since none of the fields read here span multiple lines.
+fn paragraphs(content: &str) -> Vec<Vec<(String, &str)>> {
+    let mut paragraphs = Vec::new();
+    let mut current = Vec::new();
+    for line in content.lines() {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(std::mem::take(&mut current));
+            }
+        } else if line.starts_with([' ', '\t', '#']) {
+            continue;
+        } else if let Some((field, value)) = line.split_once(':') {
+            current.push((field.trim().to_ascii_lowercase(), value.trim()));
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+    paragraphs
+}
+
+/// Build an installed package from a paragraph, or `None` if the package is
+/// not installed.
+fn parse_paragraph(fields: &[(String, &str)]) -> Result<Option<InstalledPackage>, VersError> {
+    let field = |name: &str| {
+        fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| *value)
+    };
+    let required = |name: &str| {
+        field(name)
+            .filter(|value| !value.is_empty())
+            .ok_or_else(|| {
+                VersError::InvalidData(format!(
+                    "missing {} field in dpkg status entry for '{}'",
+                    name,
+                    field("package").unwrap_or("<unknown>")
+                ))
+            })
+    };
+
+    if let Some(status) = field("status")
+        && status.split_whitespace().last() != Some("installed")
+    {
+        return Ok(None);
+    }
+
+    let package = required("package")?;
+    let version: DebVersion = required("version")?.parse()?;
+    let architecture = required("architecture")?;
+
+    // The source field may carry a version: `Source: gnutls28 (3.7.9-2)`
+    let (source, source_version) = match field("source") {
+        Some(source) if !source.is_empty() => match source.split_once('(') {
+            Some((name, rest)) => {
+                let source_version = rest.trim_end().strip_suffix(')').ok_or_else(|| {
+                    VersError::InvalidData(format!(
+                        "invalid Source field '{}' for package '{}'",
+                        source, package
+                    ))
+                })?;
+                (name.trim(), source_version.trim().parse()?)
+            }
+            None => (source, version.clone()),
+        },
+        _ => (package, version.clone()),
+    };
+
+    Ok(Some(InstalledPackage {
+        package: package.to_string(),
+        version,
+        architecture: architecture.to_string(),
+        source: source.to_string(),
+        source_version,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{affected_packages, parse_status, read_status_dir, read_status_file};
+    use crate::VersError;
+    use std::collections::HashMap;
+
+    const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/dpkg");
+
+    #[test]
+    fn test_read_status_file() {
+        let packages = read_status_file(format!("{FIXTURES}/status")).unwrap();
+        let names: Vec<_> = packages.iter().map(|p| p.package.as_str()).collect();
+        assert_eq!(
+            names,
+            ["base-files", "libssl3", "libc6", "tzdata", "libgnutls30"]
+        );
+
+        let libssl = &packages[1];
+        assert_eq!(libssl.version.to_string(), "3.0.11-1~deb12u2");
+        assert_eq!(libssl.architecture, "amd64");
+        assert_eq!(libssl.source, "openssl");
+        assert_eq!(libssl.source_version, libssl.version);
+
+        assert_eq!(packages[0].source, "base-files");
+        assert_eq!(packages[3].architecture, "all");
+
+        let gnutls = &packages[4];
+        assert_eq!(gnutls.source, "gnutls28");
+        assert_eq!(gnutls.source_version.to_string(), "3.7.9-2");
+        assert_eq!(gnutls.version.to_string(), "3.7.9-2+deb12u2");
+    }
+
+    #[test]
+    fn test_read_status_dir() {
+        let packages = read_status_dir(format!("{FIXTURES}/status.d")).unwrap();
+        let names: Vec<_> = packages.iter().map(|p| p.package.as_str()).collect();
+        assert_eq!(names, ["base-files", "libssl3"]);
+        assert_eq!(packages[1].source, "openssl");
+
+        assert!(matches!(
+            read_status_dir(format!("{FIXTURES}/missing")),
+            Err(VersError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_status_entries() {
+        for status in [
+            "Package: foo\nArchitecture: amd64\n",
+            "Version: 1.0\nArchitecture: amd64\n",
+            "Package: foo\nVersion: 1.0\n",
+            "Package: foo\nVersion: 1.0\nArchitecture: amd64\nSource: bar (1.0\n",
+        ] {
+            assert!(
+                matches!(parse_status(status), Err(VersError::InvalidData(_))),
+                "{status}"
+            );
+        }
+        assert!(matches!(
+            parse_status("Package: foo\nVersion: a1.0\nArchitecture: amd64\n"),
+            Err(VersError::InvalidVersionFormat(..))
+        ));
+        assert!(parse_status("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_affected_packages() {
+        let packages = read_status_file(format!("{FIXTURES}/status")).unwrap();
+        let ranges = HashMap::from([
+            // Matched through the source package
+            (
+                "openssl".to_string(),
+                "vers:deb/<<3.0.13-1~deb12u1".parse().unwrap(),
+            ),
+            // Matched through the binary package
+            (
+                "libc6".to_string(),
+                "vers:deb/>=2.36|<<2.36-9+deb12u7".parse().unwrap(),
+            ),
+            // The source version is not affected
+            (
+                "gnutls28".to_string(),
+                "vers:deb/>>3.7.9-2".parse().unwrap(),
+            ),
+            ("tzdata".to_string(), "vers:deb/<<2023c-1".parse().unwrap()),
+            ("libpcre3".to_string(), "vers:deb/*".parse().unwrap()),
+        ]);
+        let affected: Vec<_> = affected_packages(&packages, &ranges)
+            .unwrap()
+            .into_iter()
+            .map(|(package, range)| (package.package.as_str(), range.to_string()))
+            .collect();
+        assert_eq!(
+            affected,
+            [
+                ("libssl3", "vers:deb/<3.0.13-1~deb12u1".to_string()),
+                ("libc6", "vers:deb/>=2.36|<2.36-9+deb12u7".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,258 @@
+//! Import of Debian security tracker data.
+//!
+//! The Debian security tracker publishes its data as a JSON object mapping
+//! source package names to issues (CVE ids or `TEMP-` ids), and each issue to
+//! its state in every Debian suite:
+//!
+//! ```json
+//! { "openssl": { "CVE-2023-5678": { "releases": { "bookworm": {
+//!     "status": "resolved", "fixed_version": "3.0.13-1~deb12u1",
+//!     "urgency": "not yet assigned" } } } } }
+//! ```
+//!
+//! This module converts a local copy of that data into one
+//! [`TrackerRecord`] per package, issue and suite, with the affected
+//! versions as a `vers:deb` range.
+
+use super::{DEB_SCHEME, DebVersion};
+use crate::comparator::Comparator;
+use crate::{VersError, VersVersionRange, VersionConstraint};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// The state of an issue in a suite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrackerStatus {
+    /// The issue is fixed in `fixed_version`, or does not affect the suite
+    /// if the fixed version is `0`.
+    Resolved,
+    /// The issue is not fixed yet.
+    Open,
+    /// It is not known whether the issue affects the suite.
+    Undetermined,
+}
+
+/// The state of one issue of a source package in one Debian suite.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackerRecord {
+    /// The source package name
+    pub package: String,
+    /// The issue id, e.g. `CVE-2023-5678`
+    pub issue: String,
+    /// The suite, e.g. `bookworm`
+    pub suite: String,
+    /// The status of the issue in this suite
+    pub status: TrackerStatus,
+    /// The urgency, e.g. `low` or `not yet assigned`
+    pub urgency: String,
+    /// The affected versions, or `None` if the suite is not affected.
+    ///
+    /// A resolved issue affects the versions before its fixed version,
+    /// `vers:deb/<fixed`, while open and undetermined issues affect every
+    /// version, `vers:deb/*`.
+    pub affected: Option<VersVersionRange<DebVersion>>,
+}
+
+#[derive(Deserialize)]
+struct Issue {
+    #[serde(default)]
+    releases: BTreeMap<String, Release>,
+}
+
+#[derive(Deserialize)]
+struct Release {
+    status: String,
+    fixed_version: Option<String>,
+    #[serde(default)]
+    urgency: String,
+}
+
+/// Parse Debian security tracker JSON into records sorted by package, issue
+/// and suite.
+///
+/// # Errors
+///
+/// Returns [`VersError::InvalidData`] if the JSON does not have the
+/// tracker's structure, has an unknown status, or lacks the fixed version
+/// of a resolved issue, and a version parsing error for an invalid fixed
+/// version.
+///
+/// # Examples
+///
+/// ```
+/// use vers_rs::schemes::deb::tracker::parse_tracker_json;
+///
+/// let json = r#"{"openssl": {"CVE-2023-5678": {"releases": {
+///     "bookworm": {"status": "resolved", "fixed_version": "3.0.13-1~deb12u1", "urgency": "low"},
+///     "bullseye": {"status": "open", "urgency": "unimportant"}}}}}"#;
+/// let records = parse_tracker_json(json).unwrap();
+/// assert_eq!(records[0].suite, "bookworm");
+/// assert_eq!(records[0].affected.as_ref().unwrap().to_string(), "vers:deb/<3.0.13-1~deb12u1");
+/// assert_eq!(records[1].affected.as_ref().unwrap().to_string(), "vers:deb/*");
+/// ```
+pub fn parse_tracker_json(json: &str) -> Result<Vec<TrackerRecord>, VersError> {
+    let packages: BTreeMap<String, BTreeMap<String, Issue>> = serde_json::from_str(json)
+        .map_err(|e| VersError::InvalidData(format!("invalid security tracker JSON: {}", e)))?;
+
+    let mut records = Vec::new();
+    for (package, issues) in packages {
+        for (issue, details) in issues {
+            for (suite, release) in details.releases {
+                let status = match release.status.as_str() {
+                    "resolved" => TrackerStatus::Resolved,
+                    "open" => TrackerStatus::Open,
+                    "undetermined" => TrackerStatus::Undetermined,
+                    other => {
+                        return Err(VersError::InvalidData(format!(
+                            "unknown status '{}' of {} in {} for {}",
+                            other, issue, suite, package
+                        )));
+                    }
+                };
+
+                let constraint = match (status, release.fixed_version.as_deref()) {
+                    (TrackerStatus::Resolved, Some("0")) => None,
+                    (TrackerStatus::Resolved, Some(fixed)) => {
+                        Some(VersionConstraint::new(Comparator::LessThan, fixed.parse()?))
+                    }
+                    (TrackerStatus::Resolved, None) => {
+                        return Err(VersError::InvalidData(format!(
+                            "missing fixed_version of resolved {} in {} for {}",
+                            issue, suite, package
+                        )));
+                    }
+                    _ => Some(VersionConstraint::new(
+                        Comparator::Any,
+                        DebVersion::default(),
+                    )),
+                };
+
+                records.push(TrackerRecord {
+                    package: package.clone(),
+                    issue: issue.clone(),
+                    suite,
+                    status,
+                    urgency: release.urgency,
+                    affected: constraint
+                        .map(|c| VersVersionRange::new(DEB_SCHEME.to_string(), vec![c])),
+                });
+            }
+        }
+    }
+    Ok(records)
+}
+
+/// Read a local copy of the Debian security tracker JSON.
+///
+/// See [`parse_tracker_json`].
+pub fn read_tracker_json(path: impl AsRef<Path>) -> Result<Vec<TrackerRecord>, VersError> {
+    let path = path.as_ref();
+    let json = fs::read_to_string(path)
+        .map_err(|e| VersError::Io(format!("{}: {}", path.display(), e)))?;
+    parse_tracker_json(&json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TrackerStatus, parse_tracker_json, read_tracker_json};
+    use crate::VersError;
+    use crate::range::VersionRange;
+
+    const FIXTURE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/debian-tracker.json"
+    );
+
+    #[test]
+    fn test_read_tracker_json() {
+        let records = read_tracker_json(FIXTURE).unwrap();
+        let summary: Vec<_> = records
+            .iter()
+            .map(|r| {
+                (
+                    r.package.as_str(),
+                    r.issue.as_str(),
+                    r.suite.as_str(),
+                    r.affected.as_ref().map(|range| range.to_string()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (
+                    "glibc",
+                    "TEMP-0000000-1A2B3C",
+                    "bookworm",
+                    Some("vers:deb/*".to_string())
+                ),
+                ("openssl", "CVE-2021-3449", "bookworm", None),
+                (
+                    "openssl",
+                    "CVE-2023-5678",
+                    "bookworm",
+                    Some("vers:deb/<3.0.13-1~deb12u1".to_string())
+                ),
+                (
+                    "openssl",
+                    "CVE-2023-5678",
+                    "bullseye",
+                    Some("vers:deb/*".to_string())
+                ),
+                (
+                    "openssl",
+                    "CVE-2023-5678",
+                    "sid",
+                    Some("vers:deb/<3.0.13-1".to_string())
+                ),
+            ]
+        );
+        assert_eq!(records[0].status, TrackerStatus::Undetermined);
+        assert_eq!(records[1].status, TrackerStatus::Resolved);
+        assert_eq!(records[3].status, TrackerStatus::Open);
+        assert_eq!(records[3].urgency, "unimportant");
+    }
+
+    #[test]
+    fn test_tracker_ranges_match_installed_versions() {
+        let records = read_tracker_json(FIXTURE).unwrap();
+        let bookworm = records
+            .iter()
+            .find(|r| r.issue == "CVE-2023-5678" && r.suite == "bookworm")
+            .unwrap();
+        let range = bookworm.affected.as_ref().unwrap();
+        assert!(range.contains("3.0.11-1~deb12u2".parse().unwrap()).unwrap());
+        assert!(!range.contains("3.0.13-1~deb12u1".parse().unwrap()).unwrap());
+
+        let bullseye = records.iter().find(|r| r.suite == "bullseye").unwrap();
+        let range = bullseye.affected.as_ref().unwrap();
+        assert!(range.contains("1.1.1w-0+deb11u1".parse().unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_tracker_json() {
+        for json in [
+            "[]",
+            r#"{"openssl": {"CVE-1": {"releases": {"sid": {"status": "fixed"}}}}}"#,
+            r#"{"openssl": {"CVE-1": {"releases": {"sid": {"status": "resolved"}}}}}"#,
+            r#"{"openssl": {"CVE-1": {"releases": {"sid": {"fixed_version": "1.0"}}}}}"#,
+        ] {
+            assert!(
+                matches!(parse_tracker_json(json), Err(VersError::InvalidData(_))),
+                "{json}"
+            );
+        }
+        let json = r#"{"openssl": {"CVE-1": {"releases": {"sid": {"status": "resolved", "fixed_version": "a1"}}}}}"#;
+        assert!(matches!(
+            parse_tracker_json(json),
+            Err(VersError::InvalidVersionFormat(..))
+        ));
+        assert!(parse_tracker_json("{}").unwrap().is_empty());
+        assert!(matches!(
+            read_tracker_json("/nonexistent/tracker.json"),
+            Err(VersError::Io(_))
+        ));
+    }
+}
@@ -0,0 +1,325 @@
+use crate::comparator::Comparator;
+use crate::constraint::NativeVersionConverter;
+use crate::range::interval::{Interval, IntervalSet};
+use crate::{VersError, VersVersionRange, VersionConstraint};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Scheme identifier string for Hackage versions
+pub const HACKAGE_SCHEME: &str = "hackage";
+
+/// Macro to create InvalidVersionFormat errors for Hackage versions
+macro_rules! hackage_format_error {
+    ($s:expr, $msg:expr) => {
+        VersError::InvalidVersionFormat(HACKAGE_SCHEME.to_string(), $s.to_string(), $msg.into())
+    };
+}
+
+/// Haskell package version following the Package Versioning Policy (PVP).
+///
+/// A version is a non-empty sequence of non-negative integers without leading
+/// zeros, separated by dots. Versions compare component by component, and a
+/// version that is a prefix of another sorts first, so `1.2 < 1.2.0`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct HackageVersion(Vec<u64>);
+
+impl HackageVersion {
+    /// The numeric components of this version.
+    pub fn components(&self) -> &[u64] {
+        &self.0
+    }
+
+    /// The upper bound of `^>=`: the major version `A.B` with `B` incremented
+    /// (`1.2.3` → `1.3`, `1` → `1.1`).
+    fn major_upper_bound(&self) -> Self {
+        let major = self.0.first().copied().unwrap_or(0);
+        let minor = self.0.get(1).copied().unwrap_or(0);
+        HackageVersion(vec![major, minor + 1])
+    }
+
+    /// The upper bound of a wildcard `A.B.*`: the last given component
+    /// incremented (`1.2` → `1.3`).
+    fn wildcard_upper_bound(&self) -> Self {
+        let mut components = self.0.clone();
+        if let Some(last) = components.last_mut() {
+            *last += 1;
+        }
+        HackageVersion(components)
+    }
+}
+
+impl Default for HackageVersion {
+    fn default() -> Self {
+        HackageVersion(vec![0])
+    }
+}
+
+impl fmt::Display for HackageVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<String> = self.0.iter().map(u64::to_string).collect();
+        write!(f, "{}", parts.join("."))
+    }
+}
+
+impl FromStr for HackageVersion {
+    type Err = VersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(hackage_format_error!(s, "empty"));
+        }
+
+        s.split('.')
+            .map(|part| {
+                if part.is_empty() || !part.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(hackage_format_error!(
+                        s,
+                        format!("invalid component '{}'", part)
+                    ));
+                }
+                if part.len() > 1 && part.starts_with('0') {
+                    return Err(hackage_format_error!(
+                        s,
+                        format!("leading zero in component '{}'", part)
+                    ));
+                }
+                part.parse::<u64>()
+                    .map_err(|e| hackage_format_error!(s, format!("invalid component: {e}")))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(HackageVersion)
+    }
+}
+
+impl TryFrom<String> for HackageVersion {
+    type Error = VersError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<HackageVersion> for String {
+    fn from(v: HackageVersion) -> Self {
+        v.to_string()
+    }
+}
+
+impl NativeVersionConverter for HackageVersion {
+    const SCHEME_NAME: &'static str = HACKAGE_SCHEME;
+
+    /// Parse a Cabal version range like `>= 1 && < 2 || ^>= 3.1`.
+    ///
+    /// Supported syntax:
+    /// - The comparison operators `==`, `>`, `>=`, `<` and `<=`
+    /// - Wildcards: `== 1.2.*` means `>= 1.2 && < 1.3`
+    /// - The major bound operator: `^>= 1.2.3` means `>= 1.2.3 && < 1.3`
+    /// - `-any` and `-none`
+    /// - `&&` and `||`, where `&&` binds tighter, and parentheses
+    fn from_native_string(scheme: &str, raw: &str) -> Result<VersVersionRange<Self>, VersError> {
+        let mut parser = CabalParser { raw, rest: raw };
+        let set = parser.parse_disjunction()?;
+        parser.skip_whitespace();
+        if !parser.rest.is_empty() {
+            return Err(parser.error("unexpected input"));
+        }
+
+        let mut range = VersVersionRange::new(scheme.to_string(), set.to_constraints()?);
+        range.normalize_and_validate()?;
+        Ok(range)
+    }
+}
+
+/// Recursive descent parser for Cabal version ranges.
+struct CabalParser<'a> {
+    raw: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> CabalParser<'a> {
+    fn error(&self, msg: &str) -> VersError {
+        VersError::InvalidConstraint(format!(
+            "{} at '{}' in Cabal version range '{}'",
+            msg, self.rest, self.raw
+        ))
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        match self.rest.strip_prefix(token) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn parse_disjunction(&mut self) -> Result<IntervalSet<HackageVersion>, VersError> {
+        let mut set = self.parse_conjunction()?;
+        while self.eat("||") {
+            set = set.union(self.parse_conjunction()?);
+        }
+        Ok(set)
+    }
+
+    fn parse_conjunction(&mut self) -> Result<IntervalSet<HackageVersion>, VersError> {
+        let mut set = self.parse_atom()?;
+        while self.eat("&&") {
+            set = set.intersection(&self.parse_atom()?);
+        }
+        Ok(set)
+    }
+
+    fn parse_atom(&mut self) -> Result<IntervalSet<HackageVersion>, VersError> {
+        if self.eat("(") {
+            let set = self.parse_disjunction()?;
+            if !self.eat(")") {
+                return Err(self.error("expected ')'"));
+            }
+            return Ok(set);
+        }
+        if self.eat("-any") {
+            return Ok(IntervalSet::all());
+        }
+        if self.eat("-none") {
+            return Ok(IntervalSet::empty());
+        }
+
+        let operator = ["^>=", "==", ">=", "<=", ">", "<"]
+            .into_iter()
+            .find(|op| self.eat(op))
+            .ok_or_else(|| self.error("expected an operator"))?;
+
+        self.skip_whitespace();
+        let end = self
+            .rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '*'))
+            .unwrap_or(self.rest.len());
+        let (token, rest) = self.rest.split_at(end);
+        if token.is_empty() {
+            return Err(self.error("expected a version"));
+        }
+
+        if let Some(prefix) = token.strip_suffix(".*") {
+            if operator != "==" {
+                return Err(self.error("wildcards are only allowed with '=='"));
+            }
+            let version: HackageVersion = prefix.parse()?;
+            self.rest = rest;
+            let upper = version.wildcard_upper_bound();
+            return Ok(Interval::half_open(version, upper).into());
+        }
+
+        let version: HackageVersion = token.parse()?;
+        self.rest = rest;
+        let comparator = match operator {
+            "^>=" => {
+                let upper = version.major_upper_bound();
+                return Ok(Interval::half_open(version, upper).into());
+            }
+            "==" => Comparator::Equal,
+            ">=" => Comparator::GreaterThanOrEqual,
+            "<=" => Comparator::LessThanOrEqual,
+            ">" => Comparator::GreaterThan,
+            _ => Comparator::LessThan,
+        };
+        Ok(IntervalSet::from_constraint(&VersionConstraint::new(
+            comparator, version,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HackageVersion;
+    use crate::VersError;
+    use crate::range::VersionRange;
+    use crate::range::dynamic::DynamicVersionRange;
+
+    #[test]
+    fn test_hackage_version_ordering() {
+        // A version sorts before every version it is a prefix of
+        let versions: Vec<HackageVersion> = [
+            "0.1.0.0", "0.1.0.1", "1", "1.0", "1.2", "1.2.0", "1.9", "1.10", "1.99.99", "2",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+        for pair in versions.windows(2) {
+            assert!(pair[0] < pair[1], "{:?} < {:?}", pair[0], pair[1]);
+        }
+
+        // The PVP forbids leading zeros and tags
+        for s in ["", "1.", ".1", "1..2", "1.02", "v1", "1.2-3", "1.2.*"] {
+            assert!(s.parse::<HackageVersion>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_hackage_major_bound_and_wildcards() {
+        for (raw, expected) in [
+            ("^>= 1.2.3", "vers:hackage/>=1.2.3|<1.3"),
+            ("^>=4.14", "vers:hackage/>=4.14|<4.15"),
+            // A single component still bounds the second one
+            ("^>= 1", "vers:hackage/>=1|<1.1"),
+            ("== 1.2.*", "vers:hackage/>=1.2|<1.3"),
+            ("==1.*", "vers:hackage/>=1|<2"),
+        ] {
+            let range = DynamicVersionRange::parse_native("hackage", raw).unwrap();
+            assert_eq!(range.to_string(), expected, "{raw}");
+        }
+
+        // The wildcard matches the prefix itself and anything below it
+        let range = DynamicVersionRange::parse_native("hackage", "== 1.2.*").unwrap();
+        for version in ["1.2", "1.2.0", "1.2.10.1"] {
+            assert!(range.contains(version.to_string()).unwrap(), "{version}");
+        }
+        for version in ["1.1.9", "1.3"] {
+            assert!(!range.contains(version.to_string()).unwrap(), "{version}");
+        }
+    }
+
+    #[test]
+    fn test_hackage_operator_precedence() {
+        for (raw, expected) in [
+            (">= 1 && < 2 || ^>= 3.1", "vers:hackage/>=1|<2|>=3.1|<3.2"),
+            ("^>= 3.1 || >= 1 && < 2", "vers:hackage/>=1|<2|>=3.1|<3.2"),
+            ("(>= 1 || < 0.5) && < 2", "vers:hackage/<0.5|>=1|<2"),
+            (">= 1 || < 0.5 && < 2", "vers:hackage/<0.5|>=1"),
+            ("== 1.0 || == 1.1", "vers:hackage/1.0|1.1"),
+            ("-any", "vers:hackage/*"),
+            ("-none || >= 2", "vers:hackage/>=2"),
+        ] {
+            let range = DynamicVersionRange::parse_native("hackage", raw).unwrap();
+            assert_eq!(range.to_string(), expected, "{raw}");
+        }
+    }
+
+    #[test]
+    fn test_hackage_invalid_ranges() {
+        for raw in ["-none", ">= 2 && < 1"] {
+            assert!(
+                matches!(
+                    DynamicVersionRange::parse_native("hackage", raw),
+                    Err(VersError::InvalidRange(_))
+                ),
+                "{raw}"
+            );
+        }
+        for raw in [
+            "", ">=", "1.2", "(>= 1", ">= 1)", ">= 1 &&", ">= 1.*", ">= 1 < 2",
+        ] {
+            assert!(
+                DynamicVersionRange::parse_native("hackage", raw).is_err(),
+                "{raw}"
+            );
+        }
+    }
+}
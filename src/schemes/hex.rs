@@ -0,0 +1,224 @@
+//! Native range syntax for the Hex package manager (Elixir and Erlang).
+//!
+//! Hex versions follow SemVer 2.0.0, so the scheme reuses [`SemVer`] and only
+//! adds a converter for Elixir version requirements.
+
+use crate::VersError;
+use crate::VersionConstraint;
+use crate::comparator::Comparator;
+use crate::range::interval::{Interval, IntervalSet};
+use crate::schemes::semver::SemVer;
+
+/// Scheme identifier string for Hex versions
+pub const HEX_SCHEME: &str = "hex";
+
+const OPERATORS: [&str; 7] = ["~>", "==", "!=", ">=", "<=", ">", "<"];
+
+/// Parse an Elixir version requirement into vers constraints.
+///
+/// Supported syntax:
+/// - The comparison operators `==`, `!=`, `>`, `>=`, `<` and `<=`; a version
+///   without an operator means `==`
+/// - The pessimistic operator `~>`: `~> 2.0` means `>= 2.0.0 and < 3.0.0-0`,
+///   while `~> 2.0.1` means `>= 2.0.1 and < 2.1.0-0`
+/// - The `and` and `or` keywords, where `and` binds tighter than `or`
+///
+/// # Examples
+///
+/// ```
+/// use vers_rs::parse_native;
+///
+/// let range = parse_native("hex", "~> 2.0 or == 1.1.0").unwrap();
+/// assert_eq!(range.to_string(), "vers:hex/1.1.0|>=2.0.0|<3.0.0-0");
+/// ```
+pub fn from_native(raw: &str) -> Result<Vec<VersionConstraint<SemVer>>, VersError> {
+    let mut set = IntervalSet::empty();
+    let mut conjunction = IntervalSet::all();
+    let mut pending_operator: Option<&str> = None;
+    let mut expect_clause = true;
+
+    for word in raw.split_whitespace() {
+        match word {
+            "and" | "or" => {
+                if expect_clause {
+                    return Err(VersError::InvalidConstraint(format!(
+                        "unexpected '{}' in Elixir requirement '{}'",
+                        word, raw
+                    )));
+                }
+                if word == "or" {
+                    set = set.union(conjunction);
+                    conjunction = IntervalSet::all();
+                }
+                expect_clause = true;
+            }
+            _ => {
+                if !expect_clause {
+                    return Err(VersError::InvalidConstraint(format!(
+                        "expected 'and' or 'or' before '{}' in Elixir requirement '{}'",
+                        word, raw
+                    )));
+                }
+
+                let (operator, version) = match OPERATORS.iter().find(|op| word.starts_with(*op)) {
+                    Some(op) if pending_operator.is_none() => (*op, &word[op.len()..]),
+                    Some(_) => {
+                        return Err(VersError::InvalidConstraint(format!(
+                            "missing version in Elixir requirement '{}'",
+                            raw
+                        )));
+                    }
+                    None => (pending_operator.take().unwrap_or("=="), word),
+                };
+
+                if version.is_empty() {
+                    pending_operator = Some(operator);
+                    continue;
+                }
+
+                conjunction = conjunction.intersection(&parse_clause(operator, version)?);
+                expect_clause = false;
+            }
+        }
+    }
+
+    if expect_clause {
+        return Err(VersError::InvalidConstraint(format!(
+            "incomplete Elixir requirement '{}'",
+            raw
+        )));
+    }
+
+    set.union(conjunction).to_constraints()
+}
+
+/// Expand a single `operator version` clause into the set of matching versions.
+fn parse_clause(operator: &str, version: &str) -> Result<IntervalSet<SemVer>, VersError> {
+    if operator == "~>" {
+        return parse_pessimistic(version);
+    }
+
+    let comparator = match operator {
+        "==" => Comparator::Equal,
+        "!=" => Comparator::NotEqual,
+        ">" => Comparator::GreaterThan,
+        ">=" => Comparator::GreaterThanOrEqual,
+        "<" => Comparator::LessThan,
+        _ => Comparator::LessThanOrEqual,
+    };
+    Ok(IntervalSet::from_constraint(&VersionConstraint::new(
+        comparator,
+        version.parse()?,
+    )))
+}
+
+/// Expand `~> version`, which allows the last given component to increase.
+fn parse_pessimistic(version: &str) -> Result<IntervalSet<SemVer>, VersError> {
+    // `~> major.minor` is the only place where Elixir accepts a version
+    // without a patch component
+    if let Some((major, minor)) = version.split_once('.')
+        && !minor.contains('.')
+    {
+        let parse = |part: &str| {
+            part.parse::<u64>().map_err(|e| {
+                VersError::InvalidConstraint(format!(
+                    "invalid version '{}' in '~>' requirement: {}",
+                    version, e
+                ))
+            })
+        };
+        let major = parse(major)?;
+        let minor = parse(minor)?;
+        return Ok(Interval::half_open(
+            SemVer::new(major, minor, 0),
+            SemVer::first_prerelease(major + 1, 0, 0),
+        )
+        .into());
+    }
+
+    let lower: SemVer = version.parse()?;
+    let upper = SemVer::first_prerelease(lower.major(), lower.minor() + 1, 0);
+    Ok(Interval::half_open(lower, upper).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::VersError;
+    use crate::range::VersionRange;
+    use crate::range::dynamic::DynamicVersionRange;
+
+    #[test]
+    fn test_hex_pessimistic_operator() {
+        // `~>` lets the last given component grow and stops before the
+        // pre-releases of the next bump
+        for (raw, matching, other) in [
+            ("~> 2.0", ["2.0.0", "2.9.1"], ["1.9.9", "3.0.0-rc.1"]),
+            ("~> 2.0.1", ["2.0.1", "2.0.9"], ["2.0.1-rc.1", "2.1.0-beta"]),
+            (
+                "~>2.1.0-rc.1",
+                ["2.1.0-rc.2", "2.1.5"],
+                ["2.1.0-beta", "2.2.0-0"],
+            ),
+        ] {
+            let range = DynamicVersionRange::parse_native("hex", raw).unwrap();
+            for version in matching {
+                assert!(
+                    range.contains(version.to_string()).unwrap(),
+                    "{raw} {version}"
+                );
+            }
+            for version in other {
+                assert!(
+                    !range.contains(version.to_string()).unwrap(),
+                    "{raw} {version}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_hex_and_binds_tighter_than_or() {
+        let range =
+            DynamicVersionRange::parse_native("hex", "~> 1.2 and != 1.4.0 or >= 3.0.0").unwrap();
+        assert_eq!(
+            range.to_string(),
+            "vers:hex/>=1.2.0|!=1.4.0|<2.0.0-0|>=3.0.0"
+        );
+        assert!(range.contains("1.3.0".to_string()).unwrap());
+        assert!(!range.contains("1.4.0".to_string()).unwrap());
+        assert!(!range.contains("2.5.0".to_string()).unwrap());
+        assert!(range.contains("3.1.0".to_string()).unwrap());
+
+        // Operators may be separated from their version, and a bare version
+        // means `==`
+        for raw in ["== 1.0.0 or 1.1.0", "==1.0.0 or ==1.1.0"] {
+            let range = DynamicVersionRange::parse_native("hex", raw).unwrap();
+            assert_eq!(range.to_string(), "vers:hex/1.0.0|1.1.0", "{raw}");
+        }
+    }
+
+    #[test]
+    fn test_hex_invalid_requirements() {
+        for raw in [
+            "",
+            "and 1.0.0",
+            "1.0.0 and",
+            "1.0.0 or or 2.0.0",
+            "~>",
+            ">= ~> 1.0.0",
+            "1.0.0 2.0.0",
+            // Only `~>` accepts a version without a patch component, and it
+            // needs at least a minor one
+            "== 1.0",
+            "~> 1",
+        ] {
+            assert!(
+                matches!(
+                    DynamicVersionRange::parse_native("hex", raw),
+                    Err(VersError::InvalidConstraint(_)) | Err(VersError::InvalidVersionFormat(..))
+                ),
+                "{raw}"
+            );
+        }
+    }
+}
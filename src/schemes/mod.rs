@@ -1,2 +1,13 @@
+pub mod cocoapods;
+pub mod conan;
+pub mod conda;
+pub mod cpan;
+pub mod cran;
+pub mod dart;
 pub mod deb;
+pub mod hackage;
+pub mod hex;
+pub mod npm;
+pub mod opaque;
+pub mod openssl;
 pub mod semver;
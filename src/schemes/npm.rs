@@ -0,0 +1,700 @@
+//! Native range syntax for the npm package manager.
+//!
+//! npm versions follow SemVer 2.0.0, so the scheme reuses [`SemVer`] and only
+//! adds a converter for node-semver ranges.
+
+use crate::comparator::Comparator;
+use crate::range::VersionRange;
+use crate::range::interval::{Bound, Interval, IntervalSet};
+use crate::schemes::semver::SemVer;
+use crate::{VersError, VersVersionRange, VersionConstraint};
+
+/// Scheme identifier string for npm versions
+pub const NPM_SCHEME: &str = "npm";
+
+/// Parse a node-semver range into vers constraints.
+///
+/// Supported syntax:
+/// - Comparators `<`, `<=`, `>`, `>=` and `=`; a version without an
+///   operator means `=`
+/// - X-ranges: `*`, `1.x` and `1.2.*` match any version in place of the
+///   wildcard, as do partial versions like `1` and `1.2`
+/// - Tilde ranges: `~1.2.3` means `>=1.2.3 <1.3.0-0`, `~1` means
+///   `>=1.0.0 <2.0.0-0`
+/// - Caret ranges: `^1.2.3` means `>=1.2.3 <2.0.0-0`; for `0.x` versions the
+///   first non-zero component is fixed, so `^0.2.3` means `>=0.2.3 <0.3.0-0`
+/// - Hyphen ranges: `1.2.3 - 2.3.4` means `>=1.2.3 <=2.3.4`, and a partial
+///   upper version is treated like an x-range
+/// - Space-separated conjunctions and `||` disjunctions
+///
+/// Exclusive upper bounds exclude the pre-releases of that version, so `<2`
+/// becomes `<2.0.0-0`. For compatibility with earlier versions of this
+/// crate, an input with single `|` separators is read as vers constraints if
+/// it is a compact list of them, such as `>=1.0.0|<2.0.0`. Other inputs with
+/// a single `|` are rejected, as npm separates alternatives with `||`.
+///
+/// # Examples
+///
+/// ```
+/// use vers_rs::parse_native;
+///
+/// let range = parse_native("npm", "^1.2.3 || ~2.0.0").unwrap();
+/// assert_eq!(range.to_string(), "vers:npm/>=1.2.3|<2.0.0-0|>=2.0.0|<2.1.0-0");
+/// ```
+pub fn from_native(raw: &str) -> Result<Vec<VersionConstraint<SemVer>>, VersError> {
+    if raw.contains('|') && !raw.contains("||") {
+        return Some(raw)
+            .filter(|raw| !raw.contains(char::is_whitespace))
+            .and_then(|raw| {
+                raw.split('|')
+                    .map(VersionConstraint::parse)
+                    .collect::<Result<_, _>>()
+                    .ok()
+            })
+            .ok_or_else(|| {
+                VersError::InvalidConstraint(format!(
+                    "invalid npm range '{}': alternatives are separated by '||'",
+                    raw
+                ))
+            });
+    }
+
+    let mut set = IntervalSet::empty();
+    for range in raw.split("||") {
+        set = set.union(parse_range(range)?);
+    }
+    set.to_constraints()
+}
+
+/// Parse a range without `||`: a hyphen range or a conjunction of
+/// comparators.
+fn parse_range(range: &str) -> Result<IntervalSet<SemVer>, VersError> {
+    let tokens = tokenize(range)?;
+    if let [from, "-", to] = tokens.as_slice() {
+        return parse_hyphen(Partial::parse(from)?, Partial::parse(to)?);
+    }
+
+    let mut set = IntervalSet::all();
+    for token in tokens {
+        set = set.intersection(&parse_comparator(token)?);
+    }
+    Ok(set)
+}
+
+/// Split a range at whitespace, joining operators separated from their
+/// version by spaces (`>= 1.2.3`).
+fn tokenize(range: &str) -> Result<Vec<&str>, VersError> {
+    let mut tokens = Vec::new();
+    let mut rest = range.trim_start();
+    while !rest.is_empty() {
+        let operator_len = rest
+            .find(|c: char| !matches!(c, '<' | '>' | '=' | '~' | '^'))
+            .unwrap_or(rest.len());
+        let after_operator = rest[operator_len..].trim_start();
+        let (token, remainder) = if operator_len > 0 {
+            let version_len = after_operator
+                .find(char::is_whitespace)
+                .unwrap_or(after_operator.len());
+            if version_len == 0 {
+                return Err(VersError::InvalidConstraint(format!(
+                    "missing version after '{}' in npm range '{}'",
+                    &rest[..operator_len],
+                    range
+                )));
+            }
+            let token_len = rest.len() - after_operator.len() + version_len;
+            rest.split_at(token_len)
+        } else {
+            rest.split_at(rest.find(char::is_whitespace).unwrap_or(rest.len()))
+        };
+        tokens.push(token);
+        rest = remainder.trim_start();
+    }
+    Ok(tokens)
+}
+
+/// A possibly partial version like `1`, `1.2.x` or `1.2.3-beta.1`.
+struct Partial<'a> {
+    major: Option<u64>,
+    minor: Option<u64>,
+    patch: Option<u64>,
+    /// The version text without a leading `v`
+    text: &'a str,
+}
+
+impl<'a> Partial<'a> {
+    fn parse(raw: &'a str) -> Result<Self, VersError> {
+        let error = |msg: &str| {
+            VersError::InvalidConstraint(format!("invalid version '{}' in npm range: {}", raw, msg))
+        };
+
+        let raw = raw.trim_start();
+        let text = raw.strip_prefix(['v', 'V']).unwrap_or(raw);
+        let main_len = text.find(['-', '+']).unwrap_or(text.len());
+        let main = &text[..main_len];
+
+        let mut components = [None; 3];
+        let mut wildcard = false;
+        let parts: Vec<&str> = main.split('.').collect();
+        if parts.len() > 3 {
+            return Err(error("too many components"));
+        }
+        for (component, part) in components.iter_mut().zip(&parts) {
+            match *part {
+                "x" | "X" | "*" => wildcard = true,
+                "" => return Err(error("empty component")),
+                _ if !part.chars().all(|c| c.is_ascii_digit()) => {
+                    return Err(error("components must be numbers or wildcards"));
+                }
+                _ if part.len() > 1 && part.starts_with('0') => {
+                    return Err(error("leading zeros are not allowed"));
+                }
+                // Components after a wildcard are ignored, like npm does
+                _ if wildcard => {}
+                _ => *component = Some(part.parse().map_err(|_| error("number too large"))?),
+            }
+        }
+
+        let partial = Partial {
+            major: components[0],
+            minor: components[1],
+            patch: components[2],
+            text,
+        };
+        if main_len < text.len() && !partial.is_complete() {
+            return Err(error(
+                "pre-release and build metadata require a full version",
+            ));
+        }
+        Ok(partial)
+    }
+
+    fn is_complete(&self) -> bool {
+        self.patch.is_some()
+    }
+
+    /// The lowest version matching this partial: missing components are `0`.
+    fn floor(&self) -> Result<SemVer, VersError> {
+        if self.is_complete() {
+            return self.text.parse();
+        }
+        Ok(SemVer::new(
+            self.major.unwrap_or(0),
+            self.minor.unwrap_or(0),
+            0,
+        ))
+    }
+
+    /// The exclusive upper bound of the versions matching this partial,
+    /// e.g. `2.0.0-0` for `1.x` and `1.3.0-0` for `1.2.x`.
+    fn ceiling(&self) -> Option<SemVer> {
+        match (self.major, self.minor) {
+            (None, _) => None,
+            (Some(major), None) => Some(SemVer::first_prerelease(major + 1, 0, 0)),
+            (Some(major), Some(minor)) => Some(SemVer::first_prerelease(major, minor + 1, 0)),
+        }
+    }
+}
+
+/// Expand a single comparator like `>=1.2`, `~1.2.3` or `^0.2` into the set
+/// of matching versions.
+fn parse_comparator(token: &str) -> Result<IntervalSet<SemVer>, VersError> {
+    let (operator, version) = ["<=", ">=", "~>", "<", ">", "=", "~", "^"]
+        .into_iter()
+        .find_map(|op| token.strip_prefix(op).map(|rest| (op, rest)))
+        .unwrap_or(("", token));
+    let partial = Partial::parse(version)?;
+
+    // A wildcard major version matches everything, unless it is the bound
+    // of an exclusive comparison
+    let Some(major) = partial.major else {
+        return Ok(match operator {
+            "<" | ">" => IntervalSet::empty(),
+            _ => IntervalSet::all(),
+        });
+    };
+
+    let interval = match operator {
+        "~" | "~>" => Interval::half_open(
+            partial.floor()?,
+            SemVer::tilde_ceiling(major, partial.minor),
+        ),
+        "^" => Interval::half_open(
+            partial.floor()?,
+            SemVer::caret_ceiling(major, partial.minor, partial.patch),
+        ),
+        _ if partial.is_complete() => {
+            let comparator = match operator {
+                "<" => Comparator::LessThan,
+                "<=" => Comparator::LessThanOrEqual,
+                ">" => Comparator::GreaterThan,
+                ">=" => Comparator::GreaterThanOrEqual,
+                _ => Comparator::Equal,
+            };
+            return Ok(IntervalSet::from_constraint(&VersionConstraint::new(
+                comparator,
+                partial.floor()?,
+            )));
+        }
+        // The remaining cases are partial versions like `1` or `1.2`, whose
+        // ceiling exists since the major version is known
+        "<" => Interval::below(
+            SemVer::first_prerelease(major, partial.minor.unwrap_or(0), 0),
+            false,
+        ),
+        "<=" => Interval::below(partial.ceiling().expect("known major"), false),
+        ">" => {
+            let lower = match partial.minor {
+                Some(minor) => SemVer::new(major, minor + 1, 0),
+                None => SemVer::new(major + 1, 0, 0),
+            };
+            Interval::above(lower, true)
+        }
+        ">=" => Interval::above(partial.floor()?, true),
+        _ => Interval::half_open(partial.floor()?, partial.ceiling().expect("known major")),
+    };
+    Ok(interval.into())
+}
+
+/// Expand a hyphen range `from - to`.
+fn parse_hyphen(from: Partial, to: Partial) -> Result<IntervalSet<SemVer>, VersError> {
+    let lower = match from.major {
+        Some(_) => Interval::above(from.floor()?, true),
+        None => Interval::all(),
+    };
+    let upper = if to.is_complete() {
+        Interval::below(to.floor()?, true)
+    } else {
+        match to.ceiling() {
+            Some(ceiling) => Interval::below(ceiling, false),
+            None => Interval::all(),
+        }
+    };
+    Ok(match lower.intersect(&upper) {
+        Some(interval) => interval.into(),
+        None => IntervalSet::empty(),
+    })
+}
+
+impl VersVersionRange<SemVer> {
+    /// Check if a version is contained within this range using node-semver's
+    /// pre-release rules.
+    ///
+    /// Unlike plain SemVer ordering, npm only lets a pre-release version
+    /// match if one of the bounds of the matching interval is a pre-release
+    /// of the same `major.minor.patch`, so `>=1.0.0 <2.0.0` does not match
+    /// `1.5.0-beta.1` while `>=1.5.0-beta.0 <2.0.0` does. With
+    /// `include_prerelease`, pre-releases are matched by ordering alone,
+    /// like npm's `includePrerelease` option and [`VersionRange::contains`].
+    ///
+    /// The rules apply to the intervals of the vers range rather than to the
+    /// comparator sets of the native range it was parsed from. Overlapping
+    /// comparator sets are merged, which can drop a pre-release bound: for
+    /// `>=1.5.0-beta.0 <1.6.0 || >=1.0.0 <2.0.0`, which is the vers range
+    /// `>=1.0.0|<2.0.0`, npm matches `1.5.0-beta.1` but this method does not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::VersVersionRange;
+    /// use vers_rs::schemes::semver::SemVer;
+    ///
+    /// let range: VersVersionRange<SemVer> = "vers:npm/>=1.0.0|<2.0.0".parse().unwrap();
+    /// let nightly: SemVer = "1.5.0-beta.1".parse().unwrap();
+    /// assert!(!range.contains_npm(nightly.clone(), false).unwrap());
+    /// assert!(range.contains_npm(nightly, true).unwrap());
+    /// ```
+    pub fn contains_npm(
+        &self,
+        version: SemVer,
+        include_prerelease: bool,
+    ) -> Result<bool, VersError> {
+        if include_prerelease || !version.is_prerelease() {
+            return self.contains(version);
+        }
+
+        let same_release = |bound: &Bound<SemVer>| {
+            bound.version.is_prerelease()
+                && bound.version.major() == version.major()
+                && bound.version.minor() == version.minor()
+                && bound.version.patch() == version.patch()
+        };
+        Ok(IntervalSet::from_constraints(&self.constraints)
+            .intervals()
+            .iter()
+            .filter(|interval| interval.contains(&version))
+            .any(|interval| {
+                interval
+                    .lower
+                    .iter()
+                    .chain(&interval.upper)
+                    .any(same_release)
+            }))
+    }
+}
+
+/// How [`to_native`] handles `!=` constraints, which node-semver cannot
+/// express directly.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NotEqualPolicy {
+    /// Fail with [`VersError::InvalidConstraint`].
+    #[default]
+    Reject,
+    /// Split the surrounding interval at the excluded version, so `!=1.2.3`
+    /// becomes `<1.2.3 || >1.2.3`. The result is exact but longer.
+    Split,
+    /// Drop the exclusion. The result also matches the excluded versions.
+    Ignore,
+}
+
+/// Render a SemVer range as the shortest equivalent node-semver range.
+///
+/// Each interval of the range is written as the shortest of an x-range
+/// (`1.2.x`), a tilde range, a caret range or an explicit comparator pair,
+/// and the intervals are joined with ` || `.
+///
+/// # Errors
+///
+/// Returns [`VersError::InvalidConstraint`] if the range contains a `!=`
+/// constraint and `not_equal` is [`NotEqualPolicy::Reject`].
+///
+/// # Examples
+///
+/// ```
+/// use vers_rs::VersVersionRange;
+/// use vers_rs::schemes::npm::{self, NotEqualPolicy};
+/// use vers_rs::schemes::semver::SemVer;
+///
+/// let range: VersVersionRange<SemVer> = "vers:npm/>=1.0.0|<2.0.0|>=3.1.0".parse().unwrap();
+/// assert_eq!(
+///     npm::to_native(&range, NotEqualPolicy::Reject).unwrap(),
+///     ">=1.0.0 <2.0.0 || >=3.1.0"
+/// );
+///
+/// let range: VersVersionRange<SemVer> = "vers:npm/>=1.2.0|!=1.2.5|<1.3.0-0".parse().unwrap();
+/// assert!(npm::to_native(&range, NotEqualPolicy::Reject).is_err());
+/// assert_eq!(
+///     npm::to_native(&range, NotEqualPolicy::Split).unwrap(),
+///     ">=1.2.0 <1.2.5 || >1.2.5 <1.3.0-0"
+/// );
+/// assert_eq!(npm::to_native(&range, NotEqualPolicy::Ignore).unwrap(), "1.2.x");
+/// ```
+pub fn to_native(
+    range: &VersVersionRange<SemVer>,
+    not_equal: NotEqualPolicy,
+) -> Result<String, VersError> {
+    let constraints: Vec<_> = match not_equal {
+        NotEqualPolicy::Reject => {
+            if let Some(constraint) = range
+                .constraints
+                .iter()
+                .find(|c| c.comparator == Comparator::NotEqual)
+            {
+                return Err(VersError::InvalidConstraint(format!(
+                    "'{}{}' has no npm equivalent",
+                    constraint.comparator, constraint.version
+                )));
+            }
+            range.constraints.clone()
+        }
+        NotEqualPolicy::Split => range.constraints.clone(),
+        NotEqualPolicy::Ignore => range
+            .constraints
+            .iter()
+            .filter(|c| c.comparator != Comparator::NotEqual)
+            .cloned()
+            .collect(),
+    };
+
+    let set = IntervalSet::from_constraints(&constraints);
+    if set.is_empty() {
+        return Err(VersError::InvalidRange(
+            "range does not match any version".to_string(),
+        ));
+    }
+    Ok(set
+        .intervals()
+        .iter()
+        .map(render_interval)
+        .collect::<Vec<_>>()
+        .join(" || "))
+}
+
+/// Render a single interval in its shortest node-semver form.
+fn render_interval(interval: &Interval<SemVer>) -> String {
+    let lower = interval
+        .lower
+        .as_ref()
+        .map(|bound| render_bound(bound, ">"));
+    let upper = interval
+        .upper
+        .as_ref()
+        .map(|bound| render_bound(bound, "<"));
+    let (lower_bound, upper_bound) = match (&interval.lower, &interval.upper) {
+        (None, None) => return "*".to_string(),
+        (Some(lower), Some(upper)) => (lower, upper),
+        _ => return lower.or(upper).unwrap(),
+    };
+    if lower_bound.inclusive && upper_bound.inclusive && lower_bound.version == upper_bound.version
+    {
+        return lower_bound.version.to_string();
+    }
+
+    let mut candidates = vec![format!("{} {}", lower.unwrap(), upper.unwrap())];
+    if lower_bound.inclusive && !upper_bound.inclusive {
+        let (from, to) = (&lower_bound.version, &upper_bound.version);
+        let (major, minor, patch) = (from.major(), from.minor(), from.patch());
+        if *to == SemVer::tilde_ceiling(major, Some(minor)) {
+            candidates.push(format!("~{}", from));
+        }
+        if *to == SemVer::caret_ceiling(major, Some(minor), Some(patch)) {
+            candidates.push(format!("^{}", from));
+        }
+        if *from == SemVer::new(major, 0, 0) && *to == SemVer::tilde_ceiling(major, None) {
+            candidates.push(format!("{}.x", major));
+        }
+        if *from == SemVer::new(major, minor, 0) && *to == SemVer::tilde_ceiling(major, Some(minor))
+        {
+            candidates.push(format!("{}.{}.x", major, minor));
+        }
+    }
+    candidates.into_iter().min_by_key(String::len).unwrap()
+}
+
+/// Render an interval bound as a comparator, where `direction` is `>` for
+/// lower bounds and `<` for upper bounds.
+fn render_bound(bound: &Bound<SemVer>, direction: &str) -> String {
+    let equal = if bound.inclusive { "=" } else { "" };
+    format!("{}{}{}", direction, equal, bound.version)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::VersError;
+    use crate::range::VersionRange;
+    use crate::range::dynamic::DynamicVersionRange;
+
+    #[test]
+    fn test_npm_caret_and_tilde() {
+        // `^` keeps the first non-zero component, `~` the minor version if
+        // given; both stop before the pre-releases of the bumped version
+        for (raw, expected) in [
+            ("^1.2.3", "vers:npm/>=1.2.3|<2.0.0-0"),
+            ("^0.2.3", "vers:npm/>=0.2.3|<0.3.0-0"),
+            ("^0.0.3", "vers:npm/>=0.0.3|<0.0.4-0"),
+            ("^0.0", "vers:npm/>=0.0.0|<0.1.0-0"),
+            ("^0.0.x", "vers:npm/>=0.0.0|<0.1.0-0"),
+            ("^1.x", "vers:npm/>=1.0.0|<2.0.0-0"),
+            ("^1.2.3-beta.2", "vers:npm/>=1.2.3-beta.2|<2.0.0-0"),
+            ("~1.2.3", "vers:npm/>=1.2.3|<1.3.0-0"),
+            ("~1.2", "vers:npm/>=1.2.0|<1.3.0-0"),
+            ("~1", "vers:npm/>=1.0.0|<2.0.0-0"),
+            ("~1.2.3-beta.2", "vers:npm/>=1.2.3-beta.2|<1.3.0-0"),
+            ("~>3.2.1", "vers:npm/>=3.2.1|<3.3.0-0"),
+        ] {
+            let range = DynamicVersionRange::parse_native("npm", raw).unwrap();
+            assert_eq!(range.to_string(), expected, "{raw}");
+        }
+    }
+
+    #[test]
+    fn test_npm_partial_versions() {
+        // Missing components are wildcards: a comparison with a partial
+        // version applies to the whole block of versions it stands for
+        for (raw, expected) in [
+            ("*", "vers:npm/*"),
+            ("", "vers:npm/*"),
+            ("1.x", "vers:npm/>=1.0.0|<2.0.0-0"),
+            ("1.2.*", "vers:npm/>=1.2.0|<1.3.0-0"),
+            ("1", "vers:npm/>=1.0.0|<2.0.0-0"),
+            (">1", "vers:npm/>=2.0.0"),
+            (">1.2", "vers:npm/>=1.3.0"),
+            (">=1.2", "vers:npm/>=1.2.0"),
+            ("<1.2", "vers:npm/<1.2.0-0"),
+            ("<=1.2.x", "vers:npm/<1.3.0-0"),
+            ("=1.2.3", "vers:npm/1.2.3"),
+            ("v1.2.3", "vers:npm/1.2.3"),
+            // In hyphen ranges, a partial upper bound covers its block
+            ("1.2.3 - 2.3.4", "vers:npm/>=1.2.3|<=2.3.4"),
+            ("1.2 - 2.3.4", "vers:npm/>=1.2.0|<=2.3.4"),
+            ("1.2.3 - 2.3", "vers:npm/>=1.2.3|<2.4.0-0"),
+            ("1.2.3 - 2", "vers:npm/>=1.2.3|<3.0.0-0"),
+            ("* - 2", "vers:npm/<3.0.0-0"),
+        ] {
+            let range = DynamicVersionRange::parse_native("npm", raw).unwrap();
+            assert_eq!(range.to_string(), expected, "{raw}");
+        }
+    }
+
+    #[test]
+    fn test_npm_conjunctions_and_disjunctions() {
+        for (raw, expected) in [
+            (">=1.0.0 <2.0.0", "vers:npm/>=1.0.0|<2.0.0"),
+            (">= 1.0.0 < 2.0.0", "vers:npm/>=1.0.0|<2.0.0"),
+            (
+                "^1.2.3 || ~2.0.0",
+                "vers:npm/>=1.2.3|<2.0.0-0|>=2.0.0|<2.1.0-0",
+            ),
+            (
+                "<1.0.0 || >=1.2.0 <1.3.0 || 2.0.0",
+                "vers:npm/<1.0.0|>=1.2.0|<1.3.0|2.0.0",
+            ),
+            ("1.x || >=1.5.0", "vers:npm/>=1.0.0"),
+            // vers constraint syntax is still accepted
+            (">=1.0.0|<2.0.0", "vers:npm/>=1.0.0|<2.0.0"),
+        ] {
+            let range = DynamicVersionRange::parse_native("npm", raw).unwrap();
+            assert_eq!(range.to_string(), expected, "{raw}");
+        }
+    }
+
+    #[test]
+    fn test_npm_single_bar_is_not_a_disjunction() {
+        // None of these is a compact list of vers constraints, so a single
+        // `|` is an npm syntax error
+        for raw in ["^1.0.0 | ^2.0.0", "1.0.0 | 2.0.0", ">=1.0.0 |<2.0.0"] {
+            match DynamicVersionRange::parse_native("npm", raw) {
+                Err(VersError::InvalidConstraint(msg)) => assert!(msg.contains("'||'"), "{msg}"),
+                other => panic!("{raw}: {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_npm_invalid_ranges() {
+        for raw in [
+            ">=",
+            "^",
+            "1.2.3.4",
+            "01.2.3",
+            "1.2-beta",
+            "foo",
+            ">2.0.0 <1.0.0",
+            "1.2.3 -",
+            "^1.a",
+        ] {
+            assert!(
+                matches!(
+                    DynamicVersionRange::parse_native("npm", raw),
+                    Err(VersError::InvalidConstraint(_))
+                        | Err(VersError::InvalidVersionFormat(..))
+                        | Err(VersError::InvalidRange(_))
+                ),
+                "{raw}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_npm_to_native_shortest_form() {
+        for (raw, expected) in [
+            (">=1.0.0 <2.0.0 || >=3.1.0", ">=1.0.0 <2.0.0 || >=3.1.0"),
+            ("^1.2.3", "^1.2.3"),
+            ("^0.0.3", "^0.0.3"),
+            ("~1.2.3", "~1.2.3"),
+            ("^1.0.0", "1.x"),
+            ("~0.2.0", "0.2.x"),
+            ("=1.2.3 || 1.2.5", "1.2.3 || 1.2.5"),
+            ("*", "*"),
+            ("<1.0.0 || >2.0.0 <=3.0.0", "<1.0.0 || >2.0.0 <=3.0.0"),
+            ("1.2.3 - 2.3.4", ">=1.2.3 <=2.3.4"),
+        ] {
+            let range = DynamicVersionRange::parse_native("npm", raw).unwrap();
+            let native = range.to_native().unwrap();
+            assert_eq!(native, expected, "{raw}");
+            assert_eq!(
+                DynamicVersionRange::parse_native("npm", &native).unwrap(),
+                range
+            );
+        }
+    }
+
+    #[test]
+    fn test_npm_to_native_not_equal() {
+        use super::{NotEqualPolicy, to_native};
+        use crate::VersVersionRange;
+        use crate::schemes::semver::SemVer;
+
+        let range: VersVersionRange<SemVer> = "vers:npm/!=1.0.0".parse().unwrap();
+        assert!(matches!(
+            to_native(&range, NotEqualPolicy::Reject),
+            Err(VersError::InvalidConstraint(_))
+        ));
+        assert_eq!(
+            to_native(&range, NotEqualPolicy::Split).unwrap(),
+            "<1.0.0 || >1.0.0"
+        );
+        assert_eq!(to_native(&range, NotEqualPolicy::Ignore).unwrap(), "*");
+
+        // Rendering does not depend on the scheme name
+        let range: VersVersionRange<SemVer> = "vers:semver/>=1.0.0|<2.0.0-0".parse().unwrap();
+        assert_eq!(to_native(&range, NotEqualPolicy::Reject).unwrap(), "1.x");
+    }
+
+    #[test]
+    fn test_npm_prerelease_matching() {
+        use crate::VersVersionRange;
+        use crate::schemes::semver::SemVer;
+
+        let contains = |range: &str, version: &str, include_prerelease: bool| {
+            DynamicVersionRange::parse_native("npm", range)
+                .unwrap()
+                .contains_npm(version.to_string(), include_prerelease)
+                .unwrap()
+        };
+        assert!(!contains(">=1.0.0 <2.0.0", "1.5.0-beta.1", false));
+        assert!(contains(">=1.0.0 <2.0.0", "1.5.0-beta.1", true));
+        assert!(contains(">=1.5.0-beta.0 <2.0.0", "1.5.0-beta.1", false));
+        assert!(!contains(">=1.5.0-beta.0 <2.0.0", "1.6.0-beta.1", false));
+        assert!(contains("^1.2.3-rc.1", "1.2.3-rc.2", false));
+        assert!(contains("1.2.3-rc.1", "1.2.3-rc.1", false));
+        assert!(!contains("*", "1.0.0-alpha", false));
+        assert!(contains("*", "1.0.0-alpha", true));
+        assert!(contains(">=1.0.0 <2.0.0", "1.5.0", false));
+
+        // Each interval of a disjunction is checked separately
+        let range: VersVersionRange<SemVer> = "vers:npm/>=1.0.0-rc.1|<1.0.0|>=2.0.0|<3.0.0"
+            .parse()
+            .unwrap();
+        let version: SemVer = "2.1.0-rc.1".parse().unwrap();
+        assert!(!range.contains_npm(version, false).unwrap());
+        let version: SemVer = "1.0.0-rc.2".parse().unwrap();
+        assert!(range.contains_npm(version, false).unwrap());
+
+        // Merging overlapping comparator sets loses the pre-release bound of
+        // the first one, unlike in npm
+        let range =
+            DynamicVersionRange::parse_native("npm", ">=1.5.0-beta.0 <1.6.0 || >=1.0.0 <2.0.0")
+                .unwrap();
+        assert_eq!(range.to_string(), "vers:npm/>=1.0.0|<2.0.0");
+        assert!(
+            !range
+                .contains_npm("1.5.0-beta.1".to_string(), false)
+                .unwrap()
+        );
+
+        // Other SemVer-based schemes do not follow npm's rules
+        for (scheme, raw) in [("deb", ">=1.0"), ("semver", ">=1.0.0"), ("hex", ">= 1.0.0")] {
+            assert!(matches!(
+                DynamicVersionRange::parse_native(scheme, raw)
+                    .unwrap()
+                    .contains_npm("1.0.0".to_string(), false),
+                Err(VersError::UnsupportedVersioningScheme(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_npm_contains() {
+        let range = DynamicVersionRange::parse_native("npm", "^1.2.3 || 3.x").unwrap();
+        assert_eq!(range.versioning_scheme(), "npm");
+        assert!(range.contains("1.9.0".to_string()).unwrap());
+        assert!(range.contains("3.4.5".to_string()).unwrap());
+        assert!(!range.contains("2.0.0".to_string()).unwrap());
+        assert!(!range.contains("2.0.0-rc.1".to_string()).unwrap());
+        assert!(!range.contains("1.2.2".to_string()).unwrap());
+
+        // semver keeps the vers constraint syntax
+        assert!(DynamicVersionRange::parse_native("semver", "^1.2.3").is_err());
+    }
+}
@@ -0,0 +1,136 @@
+//! Opaque versions for versioning schemes vers-rs does not implement.
+//!
+//! Without knowing how a scheme orders its versions, the only meaningful
+//! question is whether two version strings are identical. Ranges over
+//! [`OpaqueVersion`] are therefore limited to `=`, `!=` and `*`.
+
+use crate::comparator::Comparator;
+use crate::constraint::NativeVersionConverter;
+use crate::{VersError, VersionConstraint};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Scheme identifier string for opaque versions
+///
+/// Ranges parsed through the fallback keep the scheme name of the input, so
+/// this name only appears when an `OpaqueVersion` range is built directly.
+pub const OPAQUE_SCHEME: &str = "opaque";
+
+/// A version of an unknown versioning scheme, stored as an opaque string.
+///
+/// Two opaque versions are equal only if their strings are identical. The
+/// ordering is plain string ordering; it only serves to keep constraint
+/// lists in a canonical order and says nothing about which version is newer.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct OpaqueVersion(String);
+
+impl OpaqueVersion {
+    /// The version string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for OpaqueVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for OpaqueVersion {
+    type Err = VersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || s.contains(|c: char| c.is_whitespace() || c == '|') {
+            return Err(VersError::InvalidVersionFormat(
+                OPAQUE_SCHEME.to_string(),
+                s.to_string(),
+                "must be non-empty and must not contain whitespace or '|'".to_string(),
+            ));
+        }
+        Ok(OpaqueVersion(s.to_string()))
+    }
+}
+
+impl NativeVersionConverter for OpaqueVersion {
+    const SCHEME_NAME: &'static str = OPAQUE_SCHEME;
+
+    /// Parse a vers constraint, rejecting the ordered comparators `<`, `<=`,
+    /// `>` and `>=`, which cannot be evaluated without knowing the scheme.
+    fn from_native_constraint(raw: &str) -> Result<VersionConstraint<Self>, VersError> {
+        let constraint = VersionConstraint::<Self>::parse(raw)?;
+        match constraint.comparator {
+            Comparator::Equal | Comparator::NotEqual | Comparator::Any => Ok(constraint),
+            comparator => Err(VersError::InvalidConstraint(format!(
+                "comparator '{}' in '{}' requires a known versioning scheme; \
+                 only '=', '!=' and '*' are supported for opaque versions",
+                comparator, raw
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpaqueVersion;
+    use crate::VersError;
+    use crate::range::VersionRange;
+    use crate::range::dynamic::DynamicVersionRange;
+
+    #[test]
+    fn test_opaque_version() {
+        let v: OpaqueVersion = "2.0.0-final".parse().unwrap();
+        assert_eq!(v.as_str(), "2.0.0-final");
+        assert_ne!(v, "2.0.0-FINAL".parse().unwrap());
+        assert!("".parse::<OpaqueVersion>().is_err());
+        assert!("1 0".parse::<OpaqueVersion>().is_err());
+    }
+
+    #[test]
+    fn test_fallback_equality_only() {
+        let range = DynamicVersionRange::parse_with_fallback("vers:pypi/1.0|1.1|!=1.2").unwrap();
+        assert!(range.is_fallback());
+        assert_eq!(range.versioning_scheme(), "pypi");
+        assert_eq!(range.to_string(), "vers:pypi/1.0|1.1|!=1.2");
+        assert!(range.contains("1.1".to_string()).unwrap());
+        assert!(!range.contains("1.1.0".to_string()).unwrap());
+        assert!(!range.contains("1.2".to_string()).unwrap());
+
+        let range = DynamicVersionRange::parse_with_fallback("vers:gentoo/!=1.0-r1").unwrap();
+        assert!(range.contains("1.0-r2".to_string()).unwrap());
+        assert!(!range.contains("1.0-r1".to_string()).unwrap());
+
+        let range = DynamicVersionRange::parse_with_fallback("vers:gentoo/*").unwrap();
+        assert!(range.matches_all_versions());
+        assert!(range.contains("anything".to_string()).unwrap());
+    }
+
+    #[test]
+    fn test_fallback_rejects_ordered_comparators() {
+        for s in ["vers:pypi/>=1.0", "vers:pypi/1.0|<2.0"] {
+            assert!(
+                matches!(
+                    DynamicVersionRange::parse_with_fallback(s),
+                    Err(VersError::InvalidConstraint(_))
+                ),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_fallback_is_opt_in() {
+        assert!(matches!(
+            "vers:pypi/1.0".parse::<DynamicVersionRange>(),
+            Err(VersError::UnsupportedVersioningScheme(_))
+        ));
+        let range = DynamicVersionRange::parse_with_fallback("vers:npm/>=1.0.0").unwrap();
+        assert!(!range.is_fallback());
+        assert!(range.contains("1.5.0".to_string()).unwrap());
+
+        let range = DynamicVersionRange::parse_native_with_fallback("pypi", "1.0|1.1").unwrap();
+        assert_eq!(range.to_string(), "vers:pypi/1.0|1.1");
+    }
+}
@@ -0,0 +1,331 @@
+use crate::VersError;
+use crate::constraint::NativeVersionConverter;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+/// Scheme identifier string for OpenSSL versions
+pub const OPENSSL_SCHEME: &str = "openssl";
+
+/// Macro to create InvalidVersionFormat errors for OpenSSL versions
+macro_rules! openssl_format_error {
+    ($s:expr, $msg:expr) => {
+        VersError::InvalidVersionFormat(OPENSSL_SCHEME.to_string(), $s.to_string(), $msg.into())
+    };
+}
+
+/// Pre-release tag of an OpenSSL version, e.g. the `beta2` in `3.0.0-beta2`.
+///
+/// The variants are declared in release order, so the derived `Ord` sorts
+/// `alpha` before `beta` before `pre`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OpenSslPreRelease {
+    /// `-alphaN`, used by the 3.x series
+    Alpha(u64),
+    /// `-betaN`, used by both the 1.x and 3.x series
+    Beta(u64),
+    /// `-preN`, used by the 1.1.x series
+    Pre(u64),
+}
+
+/// OpenSSL version covering both numbering schemes used by the project:
+///
+/// - Legacy `major.minor.fix[letters]` releases like `0.9.8zh`, `1.0.2u` or
+///   `1.1.1w`, where the trailing letters count bug fix releases. The letter
+///   suffix sorts after the bare release (`1.1.1` < `1.1.1a`) and continues
+///   with two-letter suffixes once `z` is exhausted (`1.0.2z` < `1.0.2za`).
+/// - Semver-like `major.minor.patch` releases from 3.0 onwards, like `3.0.13`.
+///
+/// Both series may carry a pre-release tag (`-alphaN`, `-betaN`, `-preN`),
+/// which sorts before the corresponding release.
+///
+/// The original string is preserved for display.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct OpenSslVersion {
+    value: String,
+    major: u64,
+    minor: u64,
+    patch: u64,
+    letters: String,
+    pre_release: Option<OpenSslPreRelease>,
+}
+
+impl NativeVersionConverter for OpenSslVersion {
+    const SCHEME_NAME: &'static str = OPENSSL_SCHEME;
+}
+
+impl Default for OpenSslVersion {
+    fn default() -> Self {
+        OpenSslVersion {
+            value: "0.0.0".to_string(),
+            major: 0,
+            minor: 0,
+            patch: 0,
+            letters: String::new(),
+            pre_release: None,
+        }
+    }
+}
+
+impl fmt::Display for OpenSslVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl FromStr for OpenSslVersion {
+    type Err = VersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(openssl_format_error!(s, "empty"));
+        }
+
+        let (release, pre_release) = match s.split_once('-') {
+            Some((release, tag)) => (release, Some(parse_pre_release(s, tag)?)),
+            None => (s, None),
+        };
+
+        let components: Vec<&str> = release.split('.').collect();
+        if components.len() != 3 {
+            return Err(openssl_format_error!(
+                s,
+                "expected three dot-separated components"
+            ));
+        }
+
+        let parse_number = |part: &str, name: &str| {
+            if part.is_empty() || !part.chars().all(|c| c.is_ascii_digit()) {
+                return Err(openssl_format_error!(s, format!("invalid {name} '{part}'")));
+            }
+            part.parse::<u64>()
+                .map_err(|e| openssl_format_error!(s, format!("invalid {name}: {e}")))
+        };
+
+        let major = parse_number(components[0], "major")?;
+        let minor = parse_number(components[1], "minor")?;
+
+        // The last component may carry the legacy letter suffix
+        let patch_str = components[2];
+        let split = patch_str
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(patch_str.len());
+        let (patch_digits, letters) = patch_str.split_at(split);
+        let patch = parse_number(patch_digits, "patch")?;
+
+        if !letters.chars().all(|c| c.is_ascii_lowercase()) {
+            return Err(openssl_format_error!(
+                s,
+                format!("invalid letter suffix '{letters}'")
+            ));
+        }
+
+        Ok(OpenSslVersion {
+            value: s.to_string(),
+            major,
+            minor,
+            patch,
+            letters: letters.to_string(),
+            pre_release,
+        })
+    }
+}
+
+/// Parse the pre-release tag following the `-` of an OpenSSL version.
+fn parse_pre_release(s: &str, tag: &str) -> Result<OpenSslPreRelease, VersError> {
+    let split = tag.find(|c: char| c.is_ascii_digit()).unwrap_or(tag.len());
+    let (kind, number) = tag.split_at(split);
+
+    let number = if number.is_empty() {
+        0
+    } else {
+        number
+            .parse::<u64>()
+            .map_err(|e| openssl_format_error!(s, format!("invalid pre-release number: {e}")))?
+    };
+
+    match kind {
+        "alpha" => Ok(OpenSslPreRelease::Alpha(number)),
+        "beta" => Ok(OpenSslPreRelease::Beta(number)),
+        "pre" => Ok(OpenSslPreRelease::Pre(number)),
+        _ => Err(openssl_format_error!(
+            s,
+            format!("invalid pre-release tag '{tag}'")
+        )),
+    }
+}
+
+impl Ord for OpenSslVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.major
+            .cmp(&other.major)
+            .then_with(|| self.minor.cmp(&other.minor))
+            .then_with(|| self.patch.cmp(&other.patch))
+            // Letter suffixes grow as `a` .. `z`, `za` .. `zz`, which is
+            // exactly the lexicographic order of the suffix strings
+            .then_with(|| self.letters.cmp(&other.letters))
+            .then_with(|| match (&self.pre_release, &other.pre_release) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) => a.cmp(b),
+            })
+    }
+}
+
+impl PartialOrd for OpenSslVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Equality follows the ordering, so that `3.0.0-beta` equals `3.0.0-beta0`
+impl PartialEq for OpenSslVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OpenSslVersion {}
+
+// Hash the parts that `Ord::cmp` compares, consistent with `PartialEq`
+impl Hash for OpenSslVersion {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.major.hash(state);
+        self.minor.hash(state);
+        self.patch.hash(state);
+        self.letters.hash(state);
+        self.pre_release.hash(state);
+    }
+}
+
+impl TryFrom<String> for OpenSslVersion {
+    type Error = VersError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<OpenSslVersion> for String {
+    fn from(v: OpenSslVersion) -> Self {
+        v.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpenSslVersion;
+    use crate::VersError;
+    use crate::VersVersionRange;
+    use crate::range::VersionRange;
+
+    #[test]
+    fn test_openssl_release_history_ordering() {
+        // Legacy letter releases, including the two-letter suffixes after
+        // 1.0.2z, followed by the pre-releases and releases of 3.0
+        let versions: Vec<OpenSslVersion> = [
+            "0.9.8zh",
+            "1.0.0",
+            "1.0.2",
+            "1.0.2a",
+            "1.0.2z",
+            "1.0.2za",
+            "1.0.2zh",
+            "1.1.0l",
+            "1.1.1-pre9",
+            "1.1.1",
+            "1.1.1a",
+            "1.1.1w",
+            "3.0.0-alpha1",
+            "3.0.0-alpha17",
+            "3.0.0-beta1",
+            "3.0.0",
+            "3.0.9",
+            "3.0.13",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+        for pair in versions.windows(2) {
+            assert!(pair[0] < pair[1], "{} < {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_openssl_pre_release_without_number() {
+        let beta: OpenSslVersion = "3.0.0-beta".parse().unwrap();
+        let beta0: OpenSslVersion = "3.0.0-beta0".parse().unwrap();
+        let beta1: OpenSslVersion = "3.0.0-beta1".parse().unwrap();
+        assert_eq!(beta, beta0);
+        assert!(beta < beta1);
+        assert_eq!(beta.to_string(), "3.0.0-beta");
+        assert_eq!(beta0.to_string(), "3.0.0-beta0");
+    }
+
+    #[test]
+    fn test_openssl_string_forms() {
+        for s in [
+            "1.0.2zh",
+            "1.1.1w",
+            "3.0.13",
+            "3.0.0-alpha17",
+            "3.0.0-beta",
+            "1.1.1-pre9",
+        ] {
+            let version: OpenSslVersion = s.parse().unwrap();
+            assert_eq!(version.to_string(), s);
+            let json = serde_json::to_string(&version).unwrap();
+            assert_eq!(json, format!("\"{s}\""));
+            assert_eq!(
+                serde_json::from_str::<OpenSslVersion>(&json).unwrap(),
+                version
+            );
+            let range: VersVersionRange<OpenSslVersion> =
+                format!("vers:openssl/{s}").parse().unwrap();
+            assert_eq!(range.to_string(), format!("vers:openssl/{s}"));
+        }
+
+        // Upper case or misplaced letters, missing components and tags
+        // OpenSSL never used
+        for s in [
+            "",
+            "1.1",
+            "1.1.1.1",
+            "1.1.1W",
+            "1.1a.1",
+            "1.1.x",
+            "3.0.0-rc1",
+        ] {
+            assert!(
+                matches!(
+                    s.parse::<OpenSslVersion>(),
+                    Err(VersError::InvalidVersionFormat(..))
+                ),
+                "{s}"
+            );
+        }
+        assert!(serde_json::from_str::<OpenSslVersion>(r#""1.1.1W""#).is_err());
+    }
+
+    #[test]
+    fn test_openssl_advisory_range() {
+        // An advisory fixed in 1.0.2zh and 1.1.1w, and not affecting 3.0
+        let range: VersVersionRange<OpenSslVersion> =
+            "vers:openssl/>=1.0.2|<1.0.2zh|>=1.1.1|<1.1.1w"
+                .parse()
+                .unwrap();
+        let contains = |s: &str| range.contains(s.parse().unwrap()).unwrap();
+        assert!(contains("1.0.2"));
+        assert!(contains("1.0.2zg"));
+        assert!(!contains("1.0.2zh"));
+        assert!(!contains("1.1.0l"));
+        assert!(!contains("1.1.1-pre9"));
+        assert!(contains("1.1.1v"));
+        assert!(!contains("1.1.1w"));
+        assert!(!contains("3.0.0-alpha1"));
+    }
+}
@@ -1,17 +1,275 @@
-use crate::VersError;
+use crate::comparator::Comparator;
 use crate::constraint::NativeVersionConverter;
+use crate::{VersError, VersVersionRange, VersionConstraint};
 use derive_more::Display;
-use semver::Version;
+use semver::{BuildMetadata, Prerelease, Version};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::str::FromStr;
 
 pub const SEMVER_SCHEME: &str = "semver/npm";
 
-#[derive(Display, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// A SemVer 2.0.0 version, used by the `semver`, `npm` and `hex` schemes.
+///
+/// Versions are ordered and compared by SemVer precedence, which ignores
+/// build metadata: `1.0.0+a` and `1.0.0+b` are equal. The build metadata is
+/// kept for display.
+#[derive(Display, Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
 pub struct SemVer(Version);
 
+impl SemVer {
+    /// Create a release version without pre-release or build metadata.
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+        SemVer(Version::new(major, minor, patch))
+    }
+
+    /// The major version number.
+    pub fn major(&self) -> u64 {
+        self.0.major
+    }
+
+    /// The minor version number.
+    pub fn minor(&self) -> u64 {
+        self.0.minor
+    }
+
+    /// The patch version number.
+    pub fn patch(&self) -> u64 {
+        self.0.patch
+    }
+
+    /// Check whether this version carries a pre-release identifier.
+    pub fn is_prerelease(&self) -> bool {
+        !self.0.pre.is_empty()
+    }
+
+    /// Parse a version leniently, coercing common non-SemVer forms.
+    ///
+    /// Strictly valid versions are returned unchanged. Otherwise the
+    /// following coercions are applied, and reported in the order listed:
+    /// - surrounding whitespace is removed
+    /// - a leading `=` and/or `v` is removed (`=v1.2.3`)
+    /// - leading zeros are removed from numeric components (`01.2.3`)
+    /// - missing minor and patch components are set to `0` (`1.2`, `1`)
+    /// - components after the patch version are dropped (`1.2.3.4`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::schemes::semver::{Coercion, SemVer};
+    ///
+    /// let (version, coercions) = SemVer::parse_loose("v1.2").unwrap();
+    /// assert_eq!(version.to_string(), "1.2.0");
+    /// assert_eq!(coercions, [Coercion::StrippedPrefix, Coercion::PaddedComponents]);
+    ///
+    /// let (_, coercions) = SemVer::parse_loose("1.2.3").unwrap();
+    /// assert!(coercions.is_empty());
+    /// ```
+    pub fn parse_loose(s: &str) -> Result<(Self, Vec<Coercion>), VersError> {
+        if let Ok(version) = s.parse() {
+            return Ok((version, Vec::new()));
+        }
+
+        let error = |msg: &str| {
+            VersError::InvalidVersionFormat(
+                SEMVER_SCHEME.to_string(),
+                s.to_string(),
+                msg.to_string(),
+            )
+        };
+        let mut coercions = Vec::new();
+        let mut text = s.trim();
+        if text.len() != s.len() {
+            coercions.push(Coercion::TrimmedWhitespace);
+        }
+        if let Some(rest) = text.strip_prefix('=') {
+            text = rest.trim_start();
+            coercions.push(Coercion::StrippedPrefix);
+        }
+        if let Some(rest) = text.strip_prefix(['v', 'V']) {
+            text = rest;
+            if !coercions.contains(&Coercion::StrippedPrefix) {
+                coercions.push(Coercion::StrippedPrefix);
+            }
+        }
+
+        let (main, suffix) = text.split_at(text.find(['-', '+']).unwrap_or(text.len()));
+        let mut components = Vec::new();
+        for part in main.split('.') {
+            if part.is_empty() || !part.chars().all(|c| c.is_ascii_digit()) {
+                return Err(error("components must be numbers"));
+            }
+            if part.len() > 1
+                && part.starts_with('0')
+                && !coercions.contains(&Coercion::StrippedLeadingZeros)
+            {
+                coercions.push(Coercion::StrippedLeadingZeros);
+            }
+            components.push(part.parse::<u64>().map_err(|_| error("number too large"))?);
+        }
+        match components.len() {
+            1 | 2 => {
+                components.resize(3, 0);
+                coercions.push(Coercion::PaddedComponents);
+            }
+            3 => {}
+            _ => {
+                components.truncate(3);
+                coercions.push(Coercion::DroppedComponents);
+            }
+        }
+
+        let coerced = format!(
+            "{}.{}.{}{}",
+            components[0], components[1], components[2], suffix
+        );
+        let version = Version::parse(&coerced).map_err(|e| error(&e.to_string()))?;
+        Ok((SemVer(version), coercions))
+    }
+
+    /// The lowest possible version of the `major.minor.patch` release line,
+    /// i.e. `major.minor.patch-0`. Used as the exclusive upper bound of ranges
+    /// that must not match pre-releases of the next release.
+    pub(crate) fn first_prerelease(major: u64, minor: u64, patch: u64) -> Self {
+        SemVer(Version {
+            major,
+            minor,
+            patch,
+            pre: Prerelease::new("0").expect("valid pre-release"),
+            build: BuildMetadata::EMPTY,
+        })
+    }
+
+    /// The exclusive upper bound of a tilde range: the next minor version, or
+    /// the next major version if no minor version is given.
+    pub(crate) fn tilde_ceiling(major: u64, minor: Option<u64>) -> Self {
+        match minor {
+            Some(minor) => Self::first_prerelease(major, minor + 1, 0),
+            None => Self::first_prerelease(major + 1, 0, 0),
+        }
+    }
+
+    /// The exclusive upper bound of a caret range: the next version that
+    /// increments the first non-zero component of `major.minor.patch`.
+    pub(crate) fn caret_ceiling(major: u64, minor: Option<u64>, patch: Option<u64>) -> Self {
+        match (major, minor, patch) {
+            (0, Some(0), Some(patch)) => Self::first_prerelease(0, 0, patch + 1),
+            (0, Some(minor), _) => Self::first_prerelease(0, minor + 1, 0),
+            (major, _, _) => Self::first_prerelease(major + 1, 0, 0),
+        }
+    }
+}
+
+/// Constructors for common SemVer ranges around a given version.
+///
+/// The ranges use the `semver` scheme; set `versioning_scheme` to use another
+/// SemVer-based scheme. Exclusive upper bounds exclude the pre-releases of
+/// the next release line, as npm does, so `caret(1.2.3)` is
+/// `>=1.2.3|<2.0.0-0`.
+impl VersVersionRange<SemVer> {
+    /// Versions compatible with `version` per caret rules: the first
+    /// non-zero component of `major.minor.patch` stays fixed, so `^1.2.3` is
+    /// `>=1.2.3|<2.0.0-0`, `^0.2.3` is `>=0.2.3|<0.3.0-0` and `^0.0.3` is
+    /// `>=0.0.3|<0.0.4-0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::VersVersionRange;
+    ///
+    /// let range = VersVersionRange::caret(&"0.2.3".parse().unwrap());
+    /// assert_eq!(range.to_string(), "vers:semver/>=0.2.3|<0.3.0-0");
+    /// ```
+    pub fn caret(version: &SemVer) -> Self {
+        let (major, minor, patch) = (version.major(), version.minor(), version.patch());
+        Self::half_open(
+            version.clone(),
+            SemVer::caret_ceiling(major, Some(minor), Some(patch)),
+        )
+    }
+
+    /// Versions from `version` up to the next minor release, so `~1.2.3` is
+    /// `>=1.2.3|<1.3.0-0`.
+    pub fn tilde(version: &SemVer) -> Self {
+        let upper = SemVer::tilde_ceiling(version.major(), Some(version.minor()));
+        Self::half_open(version.clone(), upper)
+    }
+
+    /// All versions with the same major version as `version`, including
+    /// pre-releases: `>=1.0.0-0|<2.0.0-0` for `1.4.7`.
+    pub fn same_major(version: &SemVer) -> Self {
+        let major = version.major();
+        Self::half_open(
+            SemVer::first_prerelease(major, 0, 0),
+            SemVer::tilde_ceiling(major, None),
+        )
+    }
+
+    /// All versions with the same major and minor version as `version`,
+    /// including pre-releases: `>=1.4.0-0|<1.5.0-0` for `1.4.7`.
+    pub fn same_minor(version: &SemVer) -> Self {
+        let (major, minor) = (version.major(), version.minor());
+        Self::half_open(
+            SemVer::first_prerelease(major, minor, 0),
+            SemVer::tilde_ceiling(major, Some(minor)),
+        )
+    }
+
+    /// All versions in the minor release line of `version` that precede it:
+    /// `>=1.4.0-0|<1.4.7` for `1.4.7`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VersError::InvalidRange`] if no version precedes `version`
+    /// in its line, which is only the case for `major.minor.0-0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vers_rs::VersVersionRange;
+    ///
+    /// let range = VersVersionRange::before_in_line(&"1.4.7".parse().unwrap()).unwrap();
+    /// assert_eq!(range.to_string(), "vers:semver/>=1.4.0-0|<1.4.7");
+    /// ```
+    pub fn before_in_line(version: &SemVer) -> Result<Self, VersError> {
+        let lower = SemVer::first_prerelease(version.major(), version.minor(), 0);
+        if *version <= lower {
+            return Err(VersError::InvalidRange(format!(
+                "no version precedes {} in its release line",
+                version
+            )));
+        }
+        Ok(Self::half_open(lower, version.clone()))
+    }
+
+    /// The range `>=lower|<upper`, where `lower < upper`.
+    fn half_open(lower: SemVer, upper: SemVer) -> Self {
+        VersVersionRange::new(
+            SemVer::SCHEME_NAME.to_string(),
+            vec![
+                VersionConstraint::new(Comparator::GreaterThanOrEqual, lower),
+                VersionConstraint::new(Comparator::LessThan, upper),
+            ],
+        )
+    }
+}
+
+/// A coercion applied by [`SemVer::parse_loose`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Coercion {
+    /// Leading or trailing whitespace was removed.
+    TrimmedWhitespace,
+    /// A leading `=` or `v` was removed.
+    StrippedPrefix,
+    /// Leading zeros were removed from a numeric component.
+    StrippedLeadingZeros,
+    /// Missing minor or patch components were set to `0`.
+    PaddedComponents,
+    /// Components after the patch version were dropped.
+    DroppedComponents,
+}
+
 impl NativeVersionConverter for SemVer {
     const SCHEME_NAME: &'static str = "semver";
 }
@@ -22,6 +280,14 @@ impl Default for SemVer {
     }
 }
 
+impl PartialEq for SemVer {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for SemVer {}
+
 impl PartialOrd for SemVer {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -30,34 +296,7 @@ impl PartialOrd for SemVer {
 
 impl Ord for SemVer {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.0.cmp(&other.0)
-    }
-
-    fn max(self, other: Self) -> Self
-    where
-        Self: Sized,
-    {
-        if self.0 >= other.0 { self } else { other }
-    }
-
-    fn min(self, other: Self) -> Self
-    where
-        Self: Sized,
-    {
-        if self.0 <= other.0 { self } else { other }
-    }
-
-    fn clamp(self, min: Self, max: Self) -> Self
-    where
-        Self: Sized,
-    {
-        if self.0 < min.0 {
-            min
-        } else if self.0 > max.0 {
-            max
-        } else {
-            self
-        }
+        self.0.cmp_precedence(&other.0)
     }
 }
 
@@ -70,3 +309,119 @@ impl FromStr for SemVer {
         })?))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Coercion, SemVer};
+    use crate::range::VersionRange;
+    use crate::range::dynamic::DynamicVersionRange;
+    use crate::{VersError, VersVersionRange};
+
+    #[test]
+    fn test_caret_and_tilde_constructors() {
+        // Caret fixes the first non-zero component, down to the patch for
+        // `0.0.x`, while tilde always allows patch releases
+        for (version, caret, tilde) in [
+            ("1.2.3", ">=1.2.3|<2.0.0-0", ">=1.2.3|<1.3.0-0"),
+            ("0.2.3", ">=0.2.3|<0.3.0-0", ">=0.2.3|<0.3.0-0"),
+            ("0.0.3", ">=0.0.3|<0.0.4-0", ">=0.0.3|<0.1.0-0"),
+            ("0.0.0", ">=0.0.0|<0.0.1-0", ">=0.0.0|<0.1.0-0"),
+            (
+                "2.3.0-rc.1",
+                ">=2.3.0-rc.1|<3.0.0-0",
+                ">=2.3.0-rc.1|<2.4.0-0",
+            ),
+        ] {
+            let version: SemVer = version.parse().unwrap();
+            let range = VersVersionRange::caret(&version);
+            assert_eq!(range.to_string(), format!("vers:semver/{caret}"));
+            let range = VersVersionRange::tilde(&version);
+            assert_eq!(range.to_string(), format!("vers:semver/{tilde}"));
+        }
+
+        // The constructors agree with the npm range syntax
+        let mut range = VersVersionRange::caret(&"0.2.3".parse().unwrap());
+        range.versioning_scheme = "npm".to_string();
+        assert_eq!(
+            DynamicVersionRange::parse_native("npm", "^0.2.3")
+                .unwrap()
+                .to_string(),
+            range.to_string()
+        );
+    }
+
+    #[test]
+    fn test_release_line_constructors() {
+        // Release lines start at their lowest pre-release, whatever the
+        // given version is
+        let version: SemVer = "1.4.7-rc.1".parse().unwrap();
+        let range = VersVersionRange::same_major(&version);
+        assert_eq!(range.to_string(), "vers:semver/>=1.0.0-0|<2.0.0-0");
+        assert!(range.contains("1.0.0-alpha".parse().unwrap()).unwrap());
+        assert!(!range.contains("2.0.0-alpha".parse().unwrap()).unwrap());
+        let range = VersVersionRange::same_minor(&version);
+        assert_eq!(range.to_string(), "vers:semver/>=1.4.0-0|<1.5.0-0");
+
+        let range = VersVersionRange::before_in_line(&"1.4.7".parse().unwrap()).unwrap();
+        assert_eq!(range.to_string(), "vers:semver/>=1.4.0-0|<1.4.7");
+        assert!(range.contains("1.4.7-rc.1".parse().unwrap()).unwrap());
+        assert!(!range.contains("1.4.7".parse().unwrap()).unwrap());
+        assert!(!range.contains("1.3.9".parse().unwrap()).unwrap());
+
+        // Only `major.minor.0-0` has no predecessor in its line
+        let range = VersVersionRange::before_in_line(&"1.4.0-alpha".parse().unwrap()).unwrap();
+        assert!(range.contains("1.4.0-0".parse().unwrap()).unwrap());
+        assert!(!range.contains("1.4.0-alpha".parse().unwrap()).unwrap());
+        assert!(VersVersionRange::before_in_line(&"1.4.0-0".parse().unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_semver_build_metadata_precedence() {
+        let range: DynamicVersionRange = "vers:semver/1.0.0".parse().unwrap();
+        assert!(range.contains("1.0.0+build.5".to_string()).unwrap());
+
+        let range: DynamicVersionRange = "vers:npm/>=1.0.0+ci.1|<2.0.0".parse().unwrap();
+        assert_eq!(range.to_string(), "vers:npm/>=1.0.0+ci.1|<2.0.0");
+        assert!(range.contains("1.0.0+ci.2".to_string()).unwrap());
+        assert!(!range.contains("2.0.0+ci.2".to_string()).unwrap());
+
+        assert!(matches!(
+            "vers:semver/1.0.0+a|1.0.0+b".parse::<DynamicVersionRange>(),
+            Err(VersError::DuplicateVersion(_))
+        ));
+    }
+
+    #[test]
+    fn test_semver_parse_loose() {
+        for (raw, expected, coercions) in [
+            ("1.2.3-rc.1", "1.2.3-rc.1", vec![]),
+            (
+                " v1.2.3",
+                "1.2.3",
+                vec![Coercion::TrimmedWhitespace, Coercion::StrippedPrefix],
+            ),
+            ("=1.2.3", "1.2.3", vec![Coercion::StrippedPrefix]),
+            (
+                "=v1",
+                "1.0.0",
+                vec![Coercion::StrippedPrefix, Coercion::PaddedComponents],
+            ),
+            ("1.2-beta", "1.2.0-beta", vec![Coercion::PaddedComponents]),
+            ("1.2.3.4", "1.2.3", vec![Coercion::DroppedComponents]),
+            ("01.2.03", "1.2.3", vec![Coercion::StrippedLeadingZeros]),
+        ] {
+            let (version, applied) = SemVer::parse_loose(raw).unwrap();
+            assert_eq!(version.to_string(), expected, "{raw}");
+            assert_eq!(applied, coercions, "{raw}");
+        }
+        for raw in ["", "v", "1.x", "1..2", "abc", "1.2.3-"] {
+            assert!(
+                matches!(
+                    SemVer::parse_loose(raw),
+                    Err(VersError::InvalidVersionFormat(..))
+                ),
+                "{raw}"
+            );
+        }
+    }
+}